@@ -0,0 +1,35 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ft_matrix::matrix::Matrix;
+
+fn square(n: usize) -> Matrix<f64> {
+    Matrix {
+        values: (0..n)
+            .map(|i| (0..n).map(|j| ((i * 7 + j * 3) % 11) as f64 - 5.).collect())
+            .collect(),
+    }
+}
+
+fn matrix_multiplication(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul_mat");
+    for n in [64, 128, 256, 512] {
+        let a = square(n);
+        let b = square(n);
+        group.bench_with_input(BenchmarkId::new("naive", n), &n, |bench, _| {
+            bench.iter(|| black_box(&a).mul_mat_naive(black_box(&b)))
+        });
+        group.bench_with_input(BenchmarkId::new("blocked", n), &n, |bench, _| {
+            bench.iter(|| black_box(&a).mul_mat_blocked(black_box(&b)))
+        });
+        group.bench_with_input(BenchmarkId::new("strassen", n), &n, |bench, _| {
+            bench.iter(|| black_box(&a).mul_mat_strassen(black_box(&b)))
+        });
+        #[cfg(feature = "rayon")]
+        group.bench_with_input(BenchmarkId::new("parallel", n), &n, |bench, _| {
+            bench.iter(|| black_box(&a).mul_mat_parallel(black_box(&b)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, matrix_multiplication);
+criterion_main!(benches);
@@ -0,0 +1,445 @@
+use std::fmt::Debug;
+
+use crate::{matrix::Matrix, numeric::Numeric, vector::Vector};
+
+// Tolerant comparisons for floating point results. NaN is never equal to
+// anything, itself included, and infinities only equal infinities of the same
+// sign. Vectors and matrices compare entry by entry and are unequal if their
+// shapes differ.
+pub trait ApproxEq {
+    type Epsilon: Copy + Debug;
+
+    fn default_epsilon() -> Self::Epsilon;
+
+    fn default_max_relative() -> Self::Epsilon;
+
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    // |a - b| <= epsilon
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+
+    // |a - b| <= epsilon, or |a - b| <= max_relative * max(|a|, |b|)
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool;
+
+    // |a - b| <= epsilon, or a and b are at most max_ulps representable values apart
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    // One line per entry for which `eq` fails, used in assertion messages.
+    fn differences<F: Fn(&Self::Epsilon, &Self::Epsilon) -> bool>(
+        &self,
+        other: &Self,
+        eq: F,
+    ) -> String;
+}
+
+macro_rules! impl_approx_eq_for_float {
+    ($t:ty, $bits:ty) => {
+        impl ApproxEq for $t {
+            type Epsilon = $t;
+
+            fn default_epsilon() -> $t {
+                <$t>::EPSILON
+            }
+
+            fn default_max_relative() -> $t {
+                <$t>::EPSILON
+            }
+
+            fn abs_diff_eq(&self, other: &$t, epsilon: $t) -> bool {
+                if self == other {
+                    return true;
+                }
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+                (self - other).abs() <= epsilon
+            }
+
+            fn relative_eq(&self, other: &$t, epsilon: $t, max_relative: $t) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+                (self - other).abs() <= self.abs().max(other.abs()) * max_relative
+            }
+
+            fn ulps_eq(&self, other: &$t, epsilon: $t, max_ulps: u32) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                if self.is_nan() || other.is_nan() || self.signum() != other.signum() {
+                    return false;
+                }
+                let (a, b) = (self.to_bits() as $bits, other.to_bits() as $bits);
+                a.abs_diff(b) <= max_ulps.into()
+            }
+
+            fn differences<F: Fn(&$t, &$t) -> bool>(&self, other: &$t, eq: F) -> String {
+                if eq(self, other) {
+                    String::new()
+                } else {
+                    format!(
+                        "left = {:?}, right = {:?}, diff = {:?}\n",
+                        self,
+                        other,
+                        self - other
+                    )
+                }
+            }
+        }
+    };
+}
+
+impl_approx_eq_for_float!(f32, i32);
+impl_approx_eq_for_float!(f64, i64);
+
+fn entry_differences<K, F>(entries: &[(String, &K, &K)], eq: F) -> String
+where
+    K: ApproxEq<Epsilon = K> + Debug,
+    F: Fn(&K, &K) -> bool,
+{
+    entries
+        .iter()
+        .filter(|(_, a, b)| !eq(a, b))
+        .map(|(position, a, b)| format!("{}: {}", position, a.differences(b, |_, _| false)))
+        .collect()
+}
+
+impl<K: Numeric + ApproxEq<Epsilon = K>> ApproxEq for Vector<K> {
+    type Epsilon = K;
+
+    fn default_epsilon() -> K {
+        K::default_epsilon()
+    }
+
+    fn default_max_relative() -> K {
+        K::default_max_relative()
+    }
+
+    fn abs_diff_eq(&self, other: &Vector<K>, epsilon: K) -> bool {
+        self.values.len() == other.values.len()
+            && self
+                .values
+                .iter()
+                .zip(&other.values)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Vector<K>, epsilon: K, max_relative: K) -> bool {
+        self.values.len() == other.values.len()
+            && self
+                .values
+                .iter()
+                .zip(&other.values)
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Vector<K>, epsilon: K, max_ulps: u32) -> bool {
+        self.values.len() == other.values.len()
+            && self
+                .values
+                .iter()
+                .zip(&other.values)
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+
+    fn differences<F: Fn(&K, &K) -> bool>(&self, other: &Vector<K>, eq: F) -> String {
+        if self.values.len() != other.values.len() {
+            return format!(
+                "lengths differ: left = {}, right = {}\n",
+                self.values.len(),
+                other.values.len()
+            );
+        }
+        let entries: Vec<(String, &K, &K)> = self
+            .values
+            .iter()
+            .zip(&other.values)
+            .enumerate()
+            .map(|(i, (a, b))| (format!("[{}]", i), a, b))
+            .collect();
+        entry_differences(&entries, eq)
+    }
+}
+
+impl<K: Numeric + ApproxEq<Epsilon = K>> Matrix<K> {
+    fn same_shape(&self, other: &Matrix<K>) -> bool {
+        self.values.len() == other.values.len()
+            && self
+                .values
+                .iter()
+                .zip(&other.values)
+                .all(|(a, b)| a.len() == b.len())
+    }
+
+    fn entries<'a>(&'a self, other: &'a Matrix<K>) -> impl Iterator<Item = (&'a K, &'a K)> {
+        self.values
+            .iter()
+            .zip(&other.values)
+            .flat_map(|(a, b)| a.iter().zip(b))
+    }
+}
+
+impl<K: Numeric + ApproxEq<Epsilon = K>> ApproxEq for Matrix<K> {
+    type Epsilon = K;
+
+    fn default_epsilon() -> K {
+        K::default_epsilon()
+    }
+
+    fn default_max_relative() -> K {
+        K::default_max_relative()
+    }
+
+    fn abs_diff_eq(&self, other: &Matrix<K>, epsilon: K) -> bool {
+        self.same_shape(other) && self.entries(other).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Matrix<K>, epsilon: K, max_relative: K) -> bool {
+        self.same_shape(other)
+            && self
+                .entries(other)
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Matrix<K>, epsilon: K, max_ulps: u32) -> bool {
+        self.same_shape(other)
+            && self
+                .entries(other)
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+
+    fn differences<F: Fn(&K, &K) -> bool>(&self, other: &Matrix<K>, eq: F) -> String {
+        if !self.same_shape(other) {
+            let shape = |m: &Matrix<K>| m.values.iter().map(|row| row.len()).collect::<Vec<_>>();
+            return format!(
+                "shapes differ: left row lengths = {:?}, right row lengths = {:?}\n",
+                shape(self),
+                shape(other)
+            );
+        }
+        let entries: Vec<(String, &K, &K)> = self
+            .values
+            .iter()
+            .zip(&other.values)
+            .enumerate()
+            .flat_map(|(i, (a, b))| {
+                a.iter()
+                    .zip(b)
+                    .enumerate()
+                    .map(move |(j, (x, y))| (format!("[{}][{}]", i, j), x, y))
+            })
+            .collect();
+        entry_differences(&entries, eq)
+    }
+}
+
+#[doc(hidden)]
+pub fn default_epsilon<T: ApproxEq>(_: &T) -> T::Epsilon {
+    T::default_epsilon()
+}
+
+#[doc(hidden)]
+pub fn default_max_relative<T: ApproxEq>(_: &T) -> T::Epsilon {
+    T::default_max_relative()
+}
+
+#[doc(hidden)]
+pub fn assertion_failed<T: ApproxEq + Debug, F: Fn(&T::Epsilon, &T::Epsilon) -> bool>(
+    comparison: &str,
+    left: &T,
+    right: &T,
+    eq: F,
+) -> ! {
+    panic!(
+        "assertion `{}` failed\n  left: {:?}\n right: {:?}\ndifferences:\n{}",
+        comparison,
+        left,
+        right,
+        left.differences(right, eq)
+    )
+}
+
+// The optional arguments are passed on as functions of the left operand so
+// their defaults can come from its type.
+#[macro_export]
+macro_rules! assert_abs_diff_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_abs_diff_eq!(@check $left, $right, $crate::approx::default_epsilon)
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::assert_abs_diff_eq!(@check $left, $right, |_| $epsilon)
+    };
+    (@check $left:expr, $right:expr, $epsilon:expr) => {{
+        let (left, right) = (&$left, &$right);
+        let epsilon = ($epsilon)(left);
+        if !$crate::approx::ApproxEq::abs_diff_eq(left, right, epsilon) {
+            $crate::approx::assertion_failed(
+                &format!("abs_diff_eq(left, right, epsilon = {:?})", epsilon),
+                left,
+                right,
+                |a, b| $crate::approx::ApproxEq::abs_diff_eq(a, b, epsilon),
+            );
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_relative_eq!(
+            @check $left,
+            $right,
+            $crate::approx::default_epsilon,
+            $crate::approx::default_max_relative
+        )
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::assert_relative_eq!(
+            @check $left,
+            $right,
+            |_| $epsilon,
+            $crate::approx::default_max_relative
+        )
+    };
+    ($left:expr, $right:expr, max_relative = $max_relative:expr $(,)?) => {
+        $crate::assert_relative_eq!(
+            @check $left,
+            $right,
+            $crate::approx::default_epsilon,
+            |_| $max_relative
+        )
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr $(,)?) => {
+        $crate::assert_relative_eq!(@check $left, $right, |_| $epsilon, |_| $max_relative)
+    };
+    (@check $left:expr, $right:expr, $epsilon:expr, $max_relative:expr) => {{
+        let (left, right) = (&$left, &$right);
+        let (epsilon, max_relative) = (($epsilon)(left), ($max_relative)(left));
+        if !$crate::approx::ApproxEq::relative_eq(left, right, epsilon, max_relative) {
+            $crate::approx::assertion_failed(
+                &format!(
+                    "relative_eq(left, right, epsilon = {:?}, max_relative = {:?})",
+                    epsilon, max_relative
+                ),
+                left,
+                right,
+                |a, b| $crate::approx::ApproxEq::relative_eq(a, b, epsilon, max_relative),
+            );
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_ulps_eq!(@check $left, $right, $crate::approx::default_epsilon, 4)
+    };
+    ($left:expr, $right:expr, max_ulps = $max_ulps:expr $(,)?) => {
+        $crate::assert_ulps_eq!(@check $left, $right, $crate::approx::default_epsilon, $max_ulps)
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_ulps = $max_ulps:expr $(,)?) => {
+        $crate::assert_ulps_eq!(@check $left, $right, |_| $epsilon, $max_ulps)
+    };
+    (@check $left:expr, $right:expr, $epsilon:expr, $max_ulps:expr) => {{
+        let (left, right) = (&$left, &$right);
+        let (epsilon, max_ulps): (_, u32) = (($epsilon)(left), $max_ulps);
+        if !$crate::approx::ApproxEq::ulps_eq(left, right, epsilon, max_ulps) {
+            $crate::approx::assertion_failed(
+                &format!(
+                    "ulps_eq(left, right, epsilon = {:?}, max_ulps = {:?})",
+                    epsilon, max_ulps
+                ),
+                left,
+                right,
+                |a, b| $crate::approx::ApproxEq::ulps_eq(a, b, epsilon, max_ulps),
+            );
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalar_comparisons_work() {
+        assert!(1f64.abs_diff_eq(&1.05, 0.1));
+        assert!(!1f64.abs_diff_eq(&1.2, 0.1));
+        assert!(1e10f64.relative_eq(&(1e10 + 1.), 0., 1e-9));
+        assert!(!1e-10f64.relative_eq(&2e-10, 0., 1e-9));
+        assert!((0.1f64 + 0.2).ulps_eq(&0.3, 0., 1));
+        assert!(!1f64.ulps_eq(&-1., 0., u32::MAX));
+        assert!(0f32.ulps_eq(&-0., 0., 0));
+        assert!(1f32.ulps_eq(&(1. + f32::EPSILON), 0., 1));
+    }
+
+    #[test]
+    fn nan_and_infinity_are_handled() {
+        assert!(!f64::NAN.abs_diff_eq(&f64::NAN, f64::INFINITY));
+        assert!(!f64::NAN.relative_eq(&1., 1., 1.));
+        assert!(!f64::NAN.ulps_eq(&f64::NAN, 0., u32::MAX));
+        assert!(f64::INFINITY.relative_eq(&f64::INFINITY, 0., 0.));
+        assert!(!f64::INFINITY.abs_diff_eq(&f64::MAX, f64::INFINITY));
+        assert!(!f64::INFINITY.ulps_eq(&f64::NEG_INFINITY, 0., u32::MAX));
+    }
+
+    #[test]
+    fn vector_and_matrix_comparisons_work() {
+        let v = Vector::from(&[1., 2.]);
+        assert!(v.abs_diff_eq(&Vector::from(&[1. + 1e-12, 2.]), 1e-10));
+        assert!(!v.abs_diff_eq(&Vector::from(&[1., 2., 3.]), 1.));
+        let m = Matrix::from(&[&[1., 2.], &[3., 4.]]);
+        assert!(m.relative_eq(&Matrix::from(&[&[1., 2.], &[3., 4. + 1e-15]]), 0., 1e-12));
+        assert!(!m.relative_eq(&Matrix::from(&[&[1., 2.]]), 1., 1.));
+        assert!(!m.ulps_eq(&Matrix::from(&[&[1., 2.], &[3., f64::NAN]]), 1., 4));
+    }
+
+    #[test]
+    fn differences_list_failing_entries() {
+        let m = Matrix::from(&[&[1., 2.], &[3., 4.]]);
+        let other = Matrix::from(&[&[1., 2.5], &[3., 4.]]);
+        assert_eq!(
+            m.differences(&other, |a, b| a.abs_diff_eq(b, 0.1)),
+            "[0][1]: left = 2.0, right = 2.5, diff = -0.5\n"
+        );
+        let v = Vector::from(&[1f32]);
+        assert_eq!(
+            v.differences(&Vector::from(&[1., 2.]), |a, b| a == b),
+            "lengths differ: left = 1, right = 2\n"
+        );
+    }
+
+    #[test]
+    fn assertion_macros_pass() {
+        crate::assert_abs_diff_eq!(0.1 + 0.2, 0.3);
+        crate::assert_abs_diff_eq!(
+            Vector::from(&[1., 2.]),
+            Vector::from(&[1.01, 2.]),
+            epsilon = 0.1
+        );
+        crate::assert_relative_eq!(
+            Matrix::from(&[&[1e6]]),
+            Matrix::from(&[&[1e6 + 1e-4]]),
+            max_relative = 1e-9
+        );
+        crate::assert_relative_eq!(1f32, 1.0000001, epsilon = 1e-6);
+        crate::assert_ulps_eq!(0.1 + 0.2, 0.3);
+        crate::assert_ulps_eq!(1f64, 1. + 2. * f64::EPSILON, max_ulps = 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "[1]: left = 2.0, right = 3.0, diff = -1.0")]
+    fn assertion_macros_report_entry_differences() {
+        crate::assert_relative_eq!(Vector::from(&[1., 2.]), Vector::from(&[1., 3.]));
+    }
+}
@@ -0,0 +1,280 @@
+use num::Float;
+
+use crate::{
+    linear_combinations::linear_combination, matrix::Matrix, numeric::Numeric, vector::Vector,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GramSchmidt {
+    Classical,
+    Modified,
+    // Classical Gram-Schmidt run twice per vector ("twice is enough"), which
+    // keeps the basis orthogonal to working precision.
+    #[default]
+    Reorthogonalized,
+}
+
+fn check_lengths<K: Numeric>(vectors: &[Vector<K>]) -> usize {
+    let len = vectors.first().map_or(0, |v| v.values.len());
+    if vectors.iter().any(|v| v.values.len() != len) {
+        panic!("Vectors must be the same length");
+    }
+    len
+}
+
+// A vector whose component outside the current basis is this small relative
+// to its own norm is treated as lying in the span.
+fn dependence_tolerance<K: Float>(len: usize) -> K {
+    K::epsilon() * K::from(10 * len.max(1)).unwrap()
+}
+
+fn subtract_projections<K: Numeric + Float>(
+    w: &mut Vector<K>,
+    basis: &[Vector<K>],
+    modified: bool,
+) {
+    if modified {
+        for q in basis {
+            let mut projection = q.clone();
+            projection.scl(w.dot(q));
+            w.sub(&projection);
+        }
+    } else {
+        let coefs: Vec<K> = basis.iter().map(|q| w.dot(q)).collect();
+        if !basis.is_empty() {
+            w.sub(&linear_combination(basis, &coefs));
+        }
+    }
+}
+
+pub fn gram_schmidt<K: Numeric + Float>(vectors: &[Vector<K>]) -> Vec<Vector<K>> {
+    gram_schmidt_with(vectors, GramSchmidt::default())
+}
+
+// Returns an orthonormal basis of the span of `vectors`; vectors that are
+// (numerically) dependent on the earlier ones are skipped.
+pub fn gram_schmidt_with<K: Numeric + Float>(
+    vectors: &[Vector<K>],
+    method: GramSchmidt,
+) -> Vec<Vector<K>> {
+    let len = check_lengths(vectors);
+    let tolerance = dependence_tolerance::<K>(len);
+    let mut basis: Vec<Vector<K>> = Vec::new();
+    for v in vectors {
+        let norm = v.norm_2();
+        if norm == K::zero() {
+            continue;
+        }
+        let mut w = v.clone();
+        match method {
+            GramSchmidt::Classical => subtract_projections(&mut w, &basis, false),
+            GramSchmidt::Modified => subtract_projections(&mut w, &basis, true),
+            GramSchmidt::Reorthogonalized => {
+                subtract_projections(&mut w, &basis, false);
+                subtract_projections(&mut w, &basis, false);
+            }
+        }
+        let remaining = w.norm_2();
+        if remaining <= tolerance * norm {
+            continue;
+        }
+        w.scl(K::one() / remaining);
+        basis.push(w);
+    }
+    basis
+}
+
+pub fn span_dimension<K: Numeric + Float>(vectors: &[Vector<K>]) -> usize {
+    gram_schmidt(vectors).len()
+}
+
+pub fn is_linearly_independent<K: Numeric + Float>(vectors: &[Vector<K>]) -> bool {
+    span_dimension(vectors) == vectors.len()
+}
+
+// Finds the coefficients c with linear_combination(basis, c) == v. A full
+// basis is solved directly through LU; a basis of a subspace goes through the
+// normal equations and the result is checked against v.
+pub fn coordinates_in_basis<K: Numeric + Float>(
+    v: &Vector<K>,
+    basis: &[Vector<K>],
+) -> Result<Vec<K>, String> {
+    let len = check_lengths(basis);
+    if !basis.is_empty() && v.values.len() != len {
+        panic!("Vector and basis dimensions don't match");
+    }
+    if !is_linearly_independent(basis) {
+        return Err("Basis vectors are linearly dependent".to_string());
+    }
+    if basis.is_empty() {
+        return if v.values.iter().all(|&x| x == K::zero()) {
+            Ok(Vec::new())
+        } else {
+            Err("Vector is not in the span of the basis".to_string())
+        };
+    }
+    let coefs = if basis.len() == len {
+        let columns = Matrix {
+            values: (0..len)
+                .map(|i| basis.iter().map(|b| b.values[i]).collect())
+                .collect(),
+        };
+        columns.lu()?.solve(v).values
+    } else {
+        let gram = Matrix {
+            values: basis
+                .iter()
+                .map(|a| basis.iter().map(|b| a.dot(b)).collect())
+                .collect(),
+        };
+        let rhs = Vector {
+            values: basis.iter().map(|b| b.dot(v)).collect(),
+        };
+        gram.lu()?.solve(&rhs).values
+    };
+    let mut residual = linear_combination(basis, &coefs);
+    residual.sub(v);
+    let scale = basis
+        .iter()
+        .zip(coefs.iter())
+        .fold(v.norm_2(), |acc, (b, c)| acc + b.norm_2() * c.abs());
+    if residual.norm_2() > K::from(len).unwrap().sqrt() * dependence_tolerance::<K>(len) * scale {
+        return Err("Vector is not in the span of the basis".to_string());
+    }
+    Ok(coefs)
+}
+
+// Returns P such that coordinates in `to` are P times coordinates in `from`.
+pub fn change_of_basis<K: Numeric + Float>(
+    from: &[Vector<K>],
+    to: &[Vector<K>],
+) -> Result<Matrix<K>, String> {
+    if from.len() != to.len() {
+        return Err("Bases must have the same number of vectors".to_string());
+    }
+    if !is_linearly_independent(from) {
+        return Err("Basis vectors are linearly dependent".to_string());
+    }
+    let columns = from
+        .iter()
+        .map(|v| coordinates_in_basis(v, to))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Matrix {
+        values: (0..to.len())
+            .map(|i| columns.iter().map(|c| c[i]).collect())
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_orthonormal(basis: &[Vector<f64>], tolerance: f64) {
+        for (i, q) in basis.iter().enumerate() {
+            for (j, r) in basis.iter().enumerate() {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((q.dot(r) - expected).abs() < tolerance);
+            }
+        }
+    }
+
+    #[test]
+    fn gram_schmidt_works() {
+        let vectors = [
+            Vector::from(&[3., 1.]),
+            Vector::from(&[2., 2.]),
+            Vector::from(&[1., 1.]),
+        ];
+        let basis = gram_schmidt(&vectors);
+        assert_eq!(basis.len(), 2);
+        assert_orthonormal(&basis, 1e-15);
+        assert!((basis[0].values[0] - 3. / 10f64.sqrt()).abs() < 1e-15);
+    }
+
+    #[test]
+    fn gram_schmidt_skips_dependent_vectors() {
+        let vectors = [
+            Vector::from(&[1., 2., 3.]),
+            Vector::from(&[2., 4., 6.]),
+            Vector::from(&[0., 0., 0.]),
+            Vector::from(&[0., 1., 0.]),
+        ];
+        for method in [
+            GramSchmidt::Classical,
+            GramSchmidt::Modified,
+            GramSchmidt::Reorthogonalized,
+        ] {
+            assert_eq!(gram_schmidt_with(&vectors, method).len(), 2);
+        }
+    }
+
+    #[test]
+    fn reorthogonalization_keeps_basis_orthogonal() {
+        // Nearly dependent columns, where classical Gram-Schmidt loses orthogonality.
+        let e = 1e-8;
+        let vectors = [
+            Vector::from(&[1., e, 0., 0.]),
+            Vector::from(&[1., 0., e, 0.]),
+            Vector::from(&[1., 0., 0., e]),
+        ];
+        let classical = gram_schmidt_with(&vectors, GramSchmidt::Classical);
+        assert!(classical[1].dot(&classical[2]).abs() > 0.1);
+        assert_orthonormal(&gram_schmidt_with(&vectors, GramSchmidt::Modified), 1e-7);
+        assert_orthonormal(&gram_schmidt(&vectors), 1e-15);
+    }
+
+    #[test]
+    fn linear_independence_works() {
+        let independent = [Vector::from(&[1., 0., 1.]), Vector::from(&[0., 1., 1.])];
+        assert!(is_linearly_independent(&independent));
+        let dependent = [
+            Vector::from(&[1., 0., 1.]),
+            Vector::from(&[0., 1., 1.]),
+            Vector::from(&[1., 1., 2.]),
+        ];
+        assert!(!is_linearly_independent(&dependent));
+        assert_eq!(span_dimension(&dependent), 2);
+    }
+
+    #[test]
+    fn coordinates_in_basis_inverts_linear_combination() {
+        let basis = [
+            Vector::from(&[1., 1., 0.]),
+            Vector::from(&[0., 1., 1.]),
+            Vector::from(&[1., 0., 1.]),
+        ];
+        let v = linear_combination(&basis, &[2., -1., 3.]);
+        let coefs = coordinates_in_basis(&v, &basis).unwrap();
+        for (c, expected) in coefs.iter().zip([2., -1., 3.]) {
+            assert!((c - expected).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn coordinates_in_subspace_basis() {
+        let basis = [Vector::from(&[1., 0., 1.]), Vector::from(&[0., 1., 1.])];
+        let v = Vector::from(&[2., 3., 5.]);
+        let coefs = coordinates_in_basis(&v, &basis).unwrap();
+        assert!((coefs[0] - 2.).abs() < 1e-14 && (coefs[1] - 3.).abs() < 1e-14);
+        assert_eq!(
+            coordinates_in_basis(&Vector::from(&[1., 0., 0.]), &basis),
+            Err("Vector is not in the span of the basis".to_string())
+        );
+        let dependent = [Vector::from(&[1., 0., 1.]), Vector::from(&[2., 0., 2.])];
+        assert!(coordinates_in_basis(&v, &dependent).is_err());
+    }
+
+    #[test]
+    fn change_of_basis_works() {
+        let standard = [Vector::from(&[1., 0.]), Vector::from(&[0., 1.])];
+        let other = [Vector::from(&[1., 1.]), Vector::from(&[1., -1.])];
+        let p = change_of_basis(&other, &standard).unwrap();
+        assert_eq!(p, Matrix::from(&[&[1., 1.], &[1., -1.]]));
+        let q = change_of_basis(&standard, &other).unwrap();
+        let coords = q.mul_vec(Vector::from(&[3., 1.]));
+        assert!((coords.values[0] - 2.).abs() < 1e-15);
+        assert!((coords.values[1] - 1.).abs() < 1e-15);
+        assert!(change_of_basis(&standard, &other[..1]).is_err());
+    }
+}
@@ -1,4 +1,7 @@
-use std::ops::{AddAssign, Div, Mul, Neg};
+use std::{
+    fmt,
+    ops::{AddAssign, Div, Mul, Neg},
+};
 
 use num::{One, Zero};
 
@@ -6,9 +9,10 @@ use crate::numeric::Numeric;
 use std::ops::{Add, Sub};
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-struct ComplexNumber {
-    real: f64,
-    imag: f64,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComplexNumber {
+    pub real: f64,
+    pub imag: f64,
 }
 
 impl Numeric for ComplexNumber {
@@ -17,9 +21,9 @@ impl Numeric for ComplexNumber {
     }
 }
 
-impl Into<f64> for ComplexNumber {
-    fn into(self) -> f64 {
-        self.magnitude()
+impl From<ComplexNumber> for f64 {
+    fn from(value: ComplexNumber) -> f64 {
+        value.magnitude()
     }
 }
 
@@ -99,3 +103,26 @@ impl Mul for ComplexNumber {
         ComplexNumber { real, imag }
     }
 }
+
+// a+bi, with the precision of the formatter applied to both parts.
+impl fmt::Display for ComplexNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.imag.is_sign_negative() {
+            '-'
+        } else {
+            '+'
+        };
+        match f.precision() {
+            Some(precision) => write!(
+                f,
+                "{:.*}{}{:.*}i",
+                precision,
+                self.real,
+                sign,
+                precision,
+                self.imag.abs()
+            ),
+            None => write!(f, "{}{}{}i", self.real, sign, self.imag.abs()),
+        }
+    }
+}
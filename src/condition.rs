@@ -0,0 +1,171 @@
+use crate::{lu::LuDecomposition, matrix::Matrix, numeric::Numeric, vector::Vector};
+
+fn norm_1<K: Numeric>(v: &Vector<K>) -> f64 {
+    v.values.iter().map(|x| x.magnitude()).sum()
+}
+
+// Builds small integers in K by repeated addition, since `Numeric` has no
+// conversion from usize.
+fn from_usize<K: Numeric>(n: usize) -> K {
+    (0..n).fold(K::zero(), |acc, _| acc + K::one())
+}
+
+// The spacing of K around one: f64::EPSILON for f64, f32::EPSILON for f32 and
+// one for integer types, where condition numbers are meaningless.
+pub(crate) fn unit_roundoff<K: Numeric>() -> f64 {
+    let two = K::one() + K::one();
+    let mut e = K::one();
+    loop {
+        let half = e / two;
+        if half == K::zero() || K::one() + half == K::one() {
+            return e.magnitude();
+        }
+        e = half;
+    }
+}
+
+impl<K: Numeric> LuDecomposition<K> {
+    // Hager's method with Higham's refinements (LAPACK's xLACON): a few solves
+    // with A and A^T give a lower bound on ||A^-1||_1 that is almost always
+    // within a factor of 3 of the true value.
+    pub fn inverse_norm_1_estimate(&self) -> f64 {
+        let n = self.size();
+        if n == 0 {
+            return 0.;
+        }
+        let inverse_n = K::one() / from_usize(n);
+        let mut x = Vector {
+            values: vec![inverse_n; n],
+        };
+        let mut estimate = 0.;
+        let mut previous_index = None;
+        for _ in 0..5 {
+            let y = self.solve(&x);
+            estimate = norm_1(&y);
+            let signs = Vector {
+                values: y
+                    .values
+                    .iter()
+                    .map(|&v| if v < K::zero() { -K::one() } else { K::one() })
+                    .collect(),
+            };
+            let z = self.solve_transpose(&signs);
+            let (index, largest) =
+                z.values
+                    .iter()
+                    .map(|v| v.magnitude())
+                    .enumerate()
+                    .fold(
+                        (0, 0.),
+                        |best, (i, m)| if m > best.1 { (i, m) } else { best },
+                    );
+            let zx = z.dot(&x);
+            if previous_index == Some(index) || (zx >= K::zero() && largest <= zx.magnitude()) {
+                break;
+            }
+            previous_index = Some(index);
+            x = Vector {
+                values: vec![K::zero(); n],
+            };
+            x.values[index] = K::one();
+        }
+
+        // Higham's extra test vector guards against the cases Hager's
+        // iteration is known to underestimate.
+        let denominator = from_usize::<K>(n.max(2) - 1);
+        let alternating = Vector {
+            values: (0..n)
+                .map(|i| {
+                    let value = K::one() + from_usize::<K>(i) / denominator;
+                    if i % 2 == 1 {
+                        -value
+                    } else {
+                        value
+                    }
+                })
+                .collect(),
+        };
+        let alternative = 2. * norm_1(&self.solve(&alternating)) / (3. * n as f64);
+        estimate.max(alternative)
+    }
+}
+
+impl<K: Numeric> Matrix<K> {
+    // Estimated 1-norm condition number; infinite for singular matrices.
+    pub fn condition_1_estimate(&self) -> f64 {
+        let lu = match self.lu() {
+            Ok(lu) => lu,
+            Err(_) => return f64::INFINITY,
+        };
+        self.condition_1_estimate_with(&lu)
+    }
+
+    pub fn condition_1_estimate_with(&self, lu: &LuDecomposition<K>) -> f64 {
+        let cols = if self.values.is_empty() {
+            0
+        } else {
+            self.values[0].len()
+        };
+        let norm = (0..cols)
+            .map(|j| self.values.iter().map(|row| row[j].magnitude()).sum())
+            .fold(0., f64::max);
+        norm * lu.inverse_norm_1_estimate()
+    }
+}
+
+// Same rule as MATLAB's warning: the reciprocal condition number is below the
+// unit roundoff of K.
+pub fn is_ill_conditioned<K: Numeric>(condition: f64) -> bool {
+    let roundoff = unit_roundoff::<K>();
+    roundoff < 1. && condition * roundoff > 1.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_is_exact_for_diagonal_matrices() {
+        let m = Matrix::from(&[&[2., 0., 0.], &[0., 0.5, 0.], &[0., 0., 4.]]);
+        assert_eq!(m.condition_1_estimate(), 4. * 2.);
+    }
+
+    #[test]
+    fn estimate_is_close_to_true_condition_number() {
+        let m = Matrix::from(&[&[8., 5., -2.], &[4., 7., 20.], &[7., 6., 1.]]);
+        let inverse = m.inverse().unwrap();
+        let exact = m.norm_1() * inverse.norm_1();
+        let estimate = m.condition_1_estimate();
+        assert!(estimate <= exact * (1. + 1e-12));
+        assert!(estimate >= exact / 3.);
+    }
+
+    #[test]
+    fn singular_matrices_have_infinite_condition_number() {
+        let m = Matrix::from(&[&[1., 2.], &[2., 4.]]);
+        assert_eq!(m.condition_1_estimate(), f64::INFINITY);
+    }
+
+    #[test]
+    fn hilbert_matrix_is_ill_conditioned() {
+        let n = 13;
+        let m = Matrix {
+            values: (0..n)
+                .map(|i| (0..n).map(|j| 1. / (i + j + 1) as f64).collect())
+                .collect(),
+        };
+        assert!(m.condition_1_estimate() > 1e16);
+        assert!(is_ill_conditioned::<f64>(m.condition_1_estimate()));
+        assert!(!is_ill_conditioned::<f64>(
+            Matrix::from(&[&[1., 0.], &[0., 1.]]).condition_1_estimate()
+        ));
+        assert!(!is_ill_conditioned::<i32>(1e20));
+    }
+
+    #[test]
+    fn unit_roundoff_matches_machine_epsilon() {
+        assert_eq!(unit_roundoff::<f64>(), f64::EPSILON);
+        assert_eq!(unit_roundoff::<f32>(), f32::EPSILON as f64);
+        assert_eq!(unit_roundoff::<i32>(), 1.);
+    }
+}
@@ -0,0 +1,356 @@
+use num::Float;
+
+use crate::{matrix::Matrix, numeric::Numeric, vector::Vector};
+
+fn shape<K: Numeric>(m: &Matrix<K>) -> (usize, usize) {
+    let cols = m.values.first().map_or(0, |row| row.len());
+    if m.values.iter().any(|row| row.len() != cols) {
+        panic!("Matrix rows must all have the same length");
+    }
+    (m.values.len(), cols)
+}
+
+impl<K: Numeric> Matrix<K> {
+    pub fn from_fn<F: FnMut(usize, usize) -> K>(rows: usize, cols: usize, mut f: F) -> Self {
+        Matrix {
+            values: (0..rows)
+                .map(|i| (0..cols).map(|j| f(i, j)).collect())
+                .collect(),
+        }
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix::from_fn(rows, cols, |_, _| K::zero())
+    }
+
+    pub fn ones(rows: usize, cols: usize) -> Self {
+        Matrix::from_fn(rows, cols, |_, _| K::one())
+    }
+
+    pub fn identity(n: usize) -> Self {
+        Matrix::from_fn(n, n, |i, j| if i == j { K::one() } else { K::zero() })
+    }
+
+    pub fn from_diagonal(diagonal: &[K]) -> Self {
+        let n = diagonal.len();
+        Matrix::from_fn(n, n, |i, j| if i == j { diagonal[i] } else { K::zero() })
+    }
+
+    pub fn from_rows(rows: &[Vector<K>]) -> Self {
+        let cols = rows.first().map_or(0, |row| row.values.len());
+        if rows.iter().any(|row| row.values.len() != cols) {
+            panic!("Rows must all have the same length");
+        }
+        Matrix {
+            values: rows.iter().map(|row| row.values.clone()).collect(),
+        }
+    }
+
+    pub fn from_columns(columns: &[Vector<K>]) -> Self {
+        let rows = columns.first().map_or(0, |column| column.values.len());
+        if columns.iter().any(|column| column.values.len() != rows) {
+            panic!("Columns must all have the same length");
+        }
+        Matrix::from_fn(rows, columns.len(), |i, j| columns[j].values[i])
+    }
+
+    pub fn hstack(blocks: &[&Matrix<K>]) -> Self {
+        let rows = blocks.first().map_or(0, |block| shape(block).0);
+        if blocks.iter().any(|block| shape(block).0 != rows) {
+            panic!("Matrices must have the same number of rows to stack them horizontally");
+        }
+        Matrix {
+            values: (0..rows)
+                .map(|i| {
+                    blocks
+                        .iter()
+                        .flat_map(|block| block.values[i].iter().copied())
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    pub fn vstack(blocks: &[&Matrix<K>]) -> Self {
+        let cols = blocks.first().map_or(0, |block| shape(block).1);
+        if blocks.iter().any(|block| shape(block).1 != cols) {
+            panic!("Matrices must have the same number of columns to stack them vertically");
+        }
+        Matrix {
+            values: blocks
+                .iter()
+                .flat_map(|block| block.values.iter().cloned())
+                .collect(),
+        }
+    }
+
+    pub fn block_diag(blocks: &[&Matrix<K>]) -> Self {
+        let shapes: Vec<(usize, usize)> = blocks.iter().map(|block| shape(block)).collect();
+        let cols: usize = shapes.iter().map(|s| s.1).sum();
+        let mut values = Vec::new();
+        let mut offset = 0;
+        for (block, &(_, block_cols)) in blocks.iter().zip(shapes.iter()) {
+            for row in &block.values {
+                let mut full = vec![K::zero(); cols];
+                full[offset..offset + block_cols].copy_from_slice(row);
+                values.push(full);
+            }
+            offset += block_cols;
+        }
+        Matrix { values }
+    }
+
+    // Row i is 1, x_i, x_i^2, ..., x_i^(n-1) with n = points.len().
+    pub fn vandermonde(points: &[K]) -> Self {
+        let n = points.len();
+        Matrix {
+            values: points
+                .iter()
+                .map(|&x| {
+                    let mut power = K::one();
+                    (0..n)
+                        .map(|_| {
+                            let value = power;
+                            power = power * x;
+                            value
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    // Constant along diagonals: T[i][j] = column[i - j] below the diagonal and
+    // row[j - i] above it.
+    pub fn toeplitz(column: &[K], row: &[K]) -> Self {
+        if column.is_empty() || row.is_empty() || column[0] != row[0] {
+            panic!("First column and row must be non-empty and share their first entry");
+        }
+        Matrix::from_fn(column.len(), row.len(), |i, j| {
+            if i >= j {
+                column[i - j]
+            } else {
+                row[j - i]
+            }
+        })
+    }
+
+    // Constant along anti-diagonals: the first column followed by the last row.
+    pub fn hankel(column: &[K], last_row: &[K]) -> Self {
+        if column.is_empty() || last_row.is_empty() || column[column.len() - 1] != last_row[0] {
+            panic!("Last entry of the column must be the first entry of the last row");
+        }
+        let rows = column.len();
+        Matrix::from_fn(rows, last_row.len(), |i, j| {
+            if i + j < rows {
+                column[i + j]
+            } else {
+                last_row[i + j + 1 - rows]
+            }
+        })
+    }
+
+    // Each row is the previous one shifted right by one, starting from `first_row`.
+    pub fn circulant(first_row: &[K]) -> Self {
+        let n = first_row.len();
+        Matrix::from_fn(n, n, |i, j| first_row[(j + n - i) % n])
+    }
+
+    // Companion matrix of the monic polynomial
+    // x^n + c[n-1] x^(n-1) + ... + c[1] x + c[0], whose eigenvalues are its roots.
+    pub fn companion(coefficients: &[K]) -> Self {
+        let n = coefficients.len();
+        if n == 0 {
+            panic!("Polynomial must have degree at least 1");
+        }
+        Matrix::from_fn(n, n, |i, j| {
+            if j == n - 1 {
+                -coefficients[i]
+            } else if i == j + 1 {
+                K::one()
+            } else {
+                K::zero()
+            }
+        })
+    }
+}
+
+impl<K: Numeric + Float> Matrix<K> {
+    pub fn hilbert(n: usize) -> Self {
+        Matrix::from_fn(n, n, |i, j| K::one() / K::from(i + j + 1).unwrap())
+    }
+}
+
+impl<K: Numeric> Vector<K> {
+    pub fn zeros(n: usize) -> Self {
+        Vector {
+            values: vec![K::zero(); n],
+        }
+    }
+
+    pub fn basis(n: usize, i: usize) -> Self {
+        if i >= n {
+            panic!("Basis index out of range");
+        }
+        let mut result = Vector::zeros(n);
+        result.values[i] = K::one();
+        result
+    }
+}
+
+impl<K: Numeric + Float> Vector<K> {
+    // `count` evenly spaced values from start to end, both included.
+    pub fn linspace(start: K, end: K, count: usize) -> Self {
+        match count {
+            0 => Vector::zeros(0),
+            1 => Vector::from(&[start]),
+            _ => {
+                let last = K::from(count - 1).unwrap();
+                let values = (0..count)
+                    .map(|i| {
+                        if i == count - 1 {
+                            end
+                        } else {
+                            let t = K::from(i).unwrap() / last;
+                            start + (end - start) * t
+                        }
+                    })
+                    .collect();
+                Vector { values }
+            }
+        }
+    }
+
+    // start, start + step, ... up to but excluding end.
+    pub fn arange(start: K, end: K, step: K) -> Self {
+        if step == K::zero() || !step.is_finite() {
+            panic!("Step must be finite and non-zero");
+        }
+        let count = ((end - start) / step).ceil();
+        let count = if count > K::zero() {
+            count.to_usize().unwrap()
+        } else {
+            0
+        };
+        Vector {
+            values: (0..count)
+                .map(|i| start + step * K::from(i).unwrap())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basic_constructors_work() {
+        assert_eq!(Matrix::<i32>::zeros(2, 3).values, vec![vec![0; 3]; 2]);
+        assert_eq!(Matrix::<i32>::ones(1, 2), Matrix::from(&[&[1, 1]]));
+        assert_eq!(Matrix::identity(2), Matrix::from(&[&[1., 0.], &[0., 1.]]));
+        assert_eq!(
+            Matrix::from_diagonal(&[2, 3]),
+            Matrix::from(&[&[2, 0], &[0, 3]])
+        );
+        assert_eq!(
+            Matrix::from_fn(2, 3, |i, j| (10 * i + j) as i32),
+            Matrix::from(&[&[0, 1, 2], &[10, 11, 12]])
+        );
+    }
+
+    #[test]
+    fn rows_and_columns_work() {
+        let a = Vector::from(&[1, 2, 3]);
+        let b = Vector::from(&[4, 5, 6]);
+        assert_eq!(
+            Matrix::from_rows(&[a.clone(), b.clone()]),
+            Matrix::from(&[&[1, 2, 3], &[4, 5, 6]])
+        );
+        assert_eq!(
+            Matrix::from_columns(&[a, b]),
+            Matrix::from(&[&[1, 4], &[2, 5], &[3, 6]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_rows_panics_on_ragged_rows() {
+        Matrix::from_rows(&[Vector::from(&[1, 2]), Vector::from(&[3])]);
+    }
+
+    #[test]
+    fn stacking_works() {
+        let a = Matrix::from(&[&[1, 2], &[3, 4]]);
+        let b = Matrix::from(&[&[5], &[6]]);
+        assert_eq!(
+            Matrix::hstack(&[&a, &b]),
+            Matrix::from(&[&[1, 2, 5], &[3, 4, 6]])
+        );
+        let c = Matrix::from(&[&[7, 8]]);
+        assert_eq!(
+            Matrix::vstack(&[&a, &c]),
+            Matrix::from(&[&[1, 2], &[3, 4], &[7, 8]])
+        );
+        assert_eq!(
+            Matrix::block_diag(&[&a, &b]),
+            Matrix::from(&[&[1, 2, 0], &[3, 4, 0], &[0, 0, 5], &[0, 0, 6]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn hstack_panics_on_row_mismatch() {
+        let a = Matrix::from(&[&[1, 2], &[3, 4]]);
+        let c = Matrix::from(&[&[7, 8]]);
+        Matrix::hstack(&[&a, &c]);
+    }
+
+    #[test]
+    fn structured_matrices_work() {
+        assert_eq!(
+            Matrix::vandermonde(&[1, 2, 3]),
+            Matrix::from(&[&[1, 1, 1], &[1, 2, 4], &[1, 3, 9]])
+        );
+        let h = Matrix::<f64>::hilbert(3);
+        assert_eq!(h.values[1], vec![1. / 2., 1. / 3., 1. / 4.]);
+        assert_eq!(
+            Matrix::toeplitz(&[1, 2, 3], &[1, 4, 5, 6]),
+            Matrix::from(&[&[1, 4, 5, 6], &[2, 1, 4, 5], &[3, 2, 1, 4]])
+        );
+        assert_eq!(
+            Matrix::hankel(&[1, 2, 3], &[3, 4, 5]),
+            Matrix::from(&[&[1, 2, 3], &[2, 3, 4], &[3, 4, 5]])
+        );
+        assert_eq!(
+            Matrix::circulant(&[1, 2, 3]),
+            Matrix::from(&[&[1, 2, 3], &[3, 1, 2], &[2, 3, 1]])
+        );
+    }
+
+    #[test]
+    fn companion_matrix_has_polynomial_roots_as_eigenvalues() {
+        // x^2 - 3x + 2 = (x - 1)(x - 2)
+        let c = Matrix::companion(&[2., -3.]);
+        assert_eq!(c, Matrix::from(&[&[0., -2.], &[1., 3.]]));
+        assert_eq!(c.trace(), 3.);
+        assert_eq!(c.lu().unwrap().determinant(), 2.);
+    }
+
+    #[test]
+    fn vector_constructors_work() {
+        assert_eq!(Vector::<i32>::zeros(3), Vector::from(&[0, 0, 0]));
+        assert_eq!(Vector::<i32>::basis(3, 1), Vector::from(&[0, 1, 0]));
+        assert_eq!(
+            Vector::linspace(0., 1., 5),
+            Vector::from(&[0., 0.25, 0.5, 0.75, 1.])
+        );
+        assert_eq!(Vector::linspace(2., 3., 1), Vector::from(&[2.]));
+        assert_eq!(
+            Vector::arange(0., 1., 0.25),
+            Vector::from(&[0., 0.25, 0.5, 0.75])
+        );
+        assert_eq!(Vector::arange(3., 0., -1.), Vector::from(&[3., 2., 1.]));
+        assert_eq!(Vector::arange(0., -1., 1.).values.len(), 0);
+    }
+}
@@ -4,7 +4,7 @@ use crate::{numeric::Numeric, vector::Vector};
 
 pub fn angle_cos<K: Numeric + Float>(u: &Vector<K>, v: &Vector<K>) -> K {
     let dot = u.dot(v);
-    dot / (v.norm_2() * v.norm_2())
+    dot / (u.norm_2() * v.norm_2())
 }
 
 #[cfg(test)]
@@ -17,4 +17,11 @@ mod tests {
         let v2 = Vector::from(&[0., 1.]);
         assert_eq!(angle_cos(&v1, &v2), 0.);
     }
+
+    #[test]
+    fn angle_cos_scales_by_both_norms() {
+        let v1 = Vector::from(&[2., 0.]);
+        let v2 = Vector::from(&[3., 3.]);
+        assert!((angle_cos(&v1, &v2) - 0.5f64.sqrt()).abs() < 1e-12);
+    }
 }
@@ -1,12 +1,50 @@
-use crate::{matrix::Matrix, numeric::Numeric};
+use crate::{condition::unit_roundoff, matrix::Matrix, numeric::Numeric};
 
 impl<K: Numeric> Matrix<K> {
+    // LU with partial pivoting for floating point types. Integer division
+    // truncates, so integer types use Bareiss elimination instead.
     pub fn determinant(&self) -> K {
-        let temp = self.clone().row_echelon();
-        temp.values
-            .iter()
-            .enumerate()
-            .fold(K::one(), |acc, (i, row)| acc * row[i])
+        if !self.is_square() {
+            panic!("Matrix must be square to calculate determinant");
+        }
+        if unit_roundoff::<K>() < 1. {
+            return match self.lu() {
+                Ok(lu) => lu.determinant(),
+                Err(_) => K::zero(),
+            };
+        }
+        self.bareiss_determinant()
+    }
+
+    // Fraction-free elimination: after step k every entry is a (k + 1) x (k + 1)
+    // minor, so each division by the previous pivot is exact.
+    fn bareiss_determinant(&self) -> K {
+        let mut m = self.values.clone();
+        let n = m.len();
+        let mut previous = K::one();
+        let mut negate = false;
+        for k in 0..n {
+            let Some(pivot) = (k..n).find(|&i| m[i][k] != K::zero()) else {
+                return K::zero();
+            };
+            if pivot != k {
+                m.swap(pivot, k);
+                negate = !negate;
+            }
+            let (top, bottom) = m.split_at_mut(k + 1);
+            let pivot_row = &top[k];
+            for row in bottom.iter_mut() {
+                for j in k + 1..n {
+                    row[j] = (row[j] * pivot_row[k] - row[k] * pivot_row[j]) / previous;
+                }
+            }
+            previous = pivot_row[k];
+        }
+        if negate {
+            -previous
+        } else {
+            previous
+        }
     }
 }
 
@@ -31,4 +69,25 @@ mod tests {
         let m = Matrix::from(&[&[2., 0., 0.], &[0., 2., 0.], &[0., 0., 2.]]);
         assert_eq!(m.determinant(), 8.);
     }
+
+    #[test]
+    fn determinant_accounts_for_row_swaps() {
+        let m = Matrix::from(&[&[0., 1.], &[1., 0.]]);
+        assert_eq!(m.determinant(), -1.);
+        let m = Matrix::from(&[&[2., 1., 3.], &[4., -1., 3.], &[-2., 5., 5.]]);
+        assert!((m.determinant() - -12f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn integer_determinants_are_exact() {
+        assert_eq!(Matrix::from(&[&[2, 1], &[1, 1]]).determinant(), 1);
+        assert_eq!(Matrix::from(&[&[3, 2], &[2, 3]]).determinant(), 5);
+        assert_eq!(Matrix::from(&[&[0, 1], &[1, 0]]).determinant(), -1);
+        let m = Matrix::from(&[&[2, 1, 3], &[4, -1, 3], &[-2, 5, 5]]);
+        assert_eq!(m.determinant(), -12);
+        let m = Matrix::from(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]]);
+        assert_eq!(m.determinant(), 0);
+        let m = Matrix::from(&[&[0, 2, 0, 1], &[3, 0, 1, 0], &[0, 1, 4, 0], &[2, 0, 0, 5]]);
+        assert_eq!(m.determinant(), -118);
+    }
 }
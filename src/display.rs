@@ -0,0 +1,307 @@
+use std::fmt;
+
+use crate::{matrix::Matrix, numeric::Numeric, vector::Vector};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Style {
+    // [1, 2]
+    // [3, 4]
+    #[default]
+    Ascii,
+    // ⎡1  2⎤
+    // ⎣3  4⎦
+    Unicode,
+    // \begin{bmatrix} ... \end{bmatrix}
+    Latex,
+    // | 0 | 1 |
+    Markdown,
+}
+
+impl Style {
+    fn row_gap(self) -> &'static str {
+        match self {
+            Style::Ascii => "...",
+            Style::Unicode => "⋮",
+            Style::Latex => "\\vdots",
+            Style::Markdown => "...",
+        }
+    }
+
+    fn col_gap(self) -> &'static str {
+        match self {
+            Style::Ascii => "...",
+            Style::Unicode => "…",
+            Style::Latex => "\\cdots",
+            Style::Markdown => "...",
+        }
+    }
+
+    fn corner_gap(self) -> &'static str {
+        match self {
+            Style::Ascii => "...",
+            Style::Unicode => "⋱",
+            Style::Latex => "\\ddots",
+            Style::Markdown => "...",
+        }
+    }
+}
+
+const DEFAULT_MAX_ENTRIES: usize = 16;
+
+// Formatting options for a matrix or vector; vectors print as a column. `{:.N}`
+// on the wrapper overrides the precision and `{:#}` selects the Unicode style
+// when no style was chosen explicitly.
+pub struct MatrixDisplay<'a, K: Numeric> {
+    rows: Vec<&'a [K]>,
+    style: Option<Style>,
+    precision: Option<usize>,
+    max_rows: usize,
+    max_cols: usize,
+}
+
+impl<'a, K: Numeric> MatrixDisplay<'a, K> {
+    fn new(rows: Vec<&'a [K]>) -> Self {
+        MatrixDisplay {
+            rows,
+            style: None,
+            precision: None,
+            max_rows: DEFAULT_MAX_ENTRIES,
+            max_cols: DEFAULT_MAX_ENTRIES,
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    // Larger matrices keep their first and last rows/columns and elide the
+    // middle. `usize::MAX` disables elision.
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows.max(2);
+        self
+    }
+
+    pub fn max_cols(mut self, max_cols: usize) -> Self {
+        self.max_cols = max_cols.max(2);
+        self
+    }
+}
+
+impl<K: Numeric> Matrix<K> {
+    pub fn display(&self) -> MatrixDisplay<'_, K> {
+        MatrixDisplay::new(self.values.iter().map(|row| row.as_slice()).collect())
+    }
+}
+
+impl<K: Numeric> Vector<K> {
+    pub fn display(&self) -> MatrixDisplay<'_, K> {
+        MatrixDisplay::new(self.values.chunks(1).collect())
+    }
+}
+
+// Indices to show out of `len`, with None marking where the elided ones were.
+fn visible(len: usize, max: usize) -> Vec<Option<usize>> {
+    if len <= max {
+        return (0..len).map(Some).collect();
+    }
+    let head = max.div_ceil(2);
+    let tail = max / 2;
+    (0..head)
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain((len - tail..len).map(Some))
+        .collect()
+}
+
+fn pad(cell: &str, width: usize) -> String {
+    let len = cell.chars().count();
+    format!("{}{}", " ".repeat(width - len), cell)
+}
+
+impl<K: Numeric + fmt::Display> fmt::Display for MatrixDisplay<'_, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let style = self.style.unwrap_or(if f.alternate() {
+            Style::Unicode
+        } else {
+            Style::Ascii
+        });
+        let precision = f.precision().or(self.precision);
+        let cols = self.rows.first().map_or(0, |row| row.len());
+        if self.rows.is_empty() || cols == 0 {
+            return match style {
+                Style::Latex => write!(f, "\\begin{{bmatrix}}\\end{{bmatrix}}"),
+                _ => write!(f, "[]"),
+            };
+        }
+        let row_indices = visible(self.rows.len(), self.max_rows);
+        let col_indices = visible(cols, self.max_cols);
+        let cells: Vec<Vec<String>> = row_indices
+            .iter()
+            .map(|&i| {
+                col_indices
+                    .iter()
+                    .map(|&j| match (i, j) {
+                        (Some(i), Some(j)) => match precision {
+                            Some(p) => format!("{:.*}", p, self.rows[i][j]),
+                            None => format!("{}", self.rows[i][j]),
+                        },
+                        (None, None) => style.corner_gap().to_string(),
+                        (None, _) => style.row_gap().to_string(),
+                        (_, None) => style.col_gap().to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut headers: Vec<String> = col_indices
+            .iter()
+            .map(|j| j.map_or(style.col_gap().to_string(), |j| j.to_string()))
+            .collect();
+        let widths: Vec<usize> = (0..col_indices.len())
+            .map(|j| {
+                // Markdown needs room for the header and at least "---" below it.
+                let minimum = if style == Style::Markdown {
+                    headers[j].chars().count().max(3)
+                } else {
+                    0
+                };
+                cells
+                    .iter()
+                    .map(|row| row[j].chars().count())
+                    .fold(minimum, usize::max)
+            })
+            .collect();
+        let join = |row: &[String], separator: &str| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, &width)| pad(cell, width))
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+        let last = cells.len() - 1;
+        let lines: Vec<String> = match style {
+            Style::Ascii => cells
+                .iter()
+                .map(|row| format!("[{}]", join(row, ", ")))
+                .collect(),
+            Style::Unicode => cells
+                .iter()
+                .enumerate()
+                .map(|(i, row)| {
+                    let (open, close) = match i {
+                        _ if last == 0 => ('[', ']'),
+                        0 => ('⎡', '⎤'),
+                        _ if i == last => ('⎣', '⎦'),
+                        _ => ('⎢', '⎥'),
+                    };
+                    format!("{}{}{}", open, join(row, "  "), close)
+                })
+                .collect(),
+            Style::Latex => std::iter::once("\\begin{bmatrix}".to_string())
+                .chain(cells.iter().enumerate().map(|(i, row)| {
+                    let end = if i == last { "" } else { " \\\\" };
+                    format!("    {}{}", join(row, " & "), end)
+                }))
+                .chain(std::iter::once("\\end{bmatrix}".to_string()))
+                .collect(),
+            Style::Markdown => {
+                let rule: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+                headers = headers
+                    .iter()
+                    .zip(&widths)
+                    .map(|(h, &w)| pad(h, w))
+                    .collect();
+                std::iter::once(format!("| {} |", headers.join(" | ")))
+                    .chain(std::iter::once(format!("|-{}-|", rule.join("-|-"))))
+                    .chain(cells.iter().map(|row| format!("| {} |", join(row, " | "))))
+                    .collect()
+            }
+        };
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+impl<K: Numeric + fmt::Display> fmt::Display for Matrix<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.display(), f)
+    }
+}
+
+impl<K: Numeric + fmt::Display> fmt::Display for Vector<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.display(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex_number::ComplexNumber;
+
+    #[test]
+    fn ascii_display_aligns_columns() {
+        let m = Matrix::from(&[&[1., -20.5], &[300., 4.]]);
+        assert_eq!(format!("{}", m), "[  1, -20.5]\n[300,     4]");
+        assert_eq!(format!("{:.2}", m), "[  1.00, -20.50]\n[300.00,   4.00]");
+        assert_eq!(format!("{}", Vector::from(&[1, 20])), "[ 1]\n[20]");
+    }
+
+    #[test]
+    fn unicode_display_uses_box_brackets() {
+        let m = Matrix::from(&[&[1, 2], &[3, 4], &[5, 6]]);
+        assert_eq!(format!("{:#}", m), "⎡1  2⎤\n⎢3  4⎥\n⎣5  6⎦");
+        assert_eq!(format!("{:#}", Matrix::from(&[&[1, 2]])), "[1  2]");
+    }
+
+    #[test]
+    fn latex_and_markdown_styles_work() {
+        let m = Matrix::from(&[&[1, 2], &[3, 4]]);
+        assert_eq!(
+            m.display().style(Style::Latex).to_string(),
+            "\\begin{bmatrix}\n    1 & 2 \\\\\n    3 & 4\n\\end{bmatrix}"
+        );
+        assert_eq!(
+            format!(
+                "{:.1}",
+                Matrix::from(&[&[1., 2.], &[3., 4.]])
+                    .display()
+                    .style(Style::Markdown)
+            ),
+            "|   0 |   1 |\n|-----|-----|\n| 1.0 | 2.0 |\n| 3.0 | 4.0 |"
+        );
+    }
+
+    #[test]
+    fn large_matrices_are_elided() {
+        let m = Matrix::from_fn(6, 7, |i, j| (10 * i + j) as i32);
+        assert_eq!(
+            m.display().max_rows(3).max_cols(4).to_string(),
+            "[  0,   1, ...,   5,   6]\n[ 10,  11, ...,  15,  16]\n[..., ..., ..., ..., ...]\n[ 50,  51, ...,  55,  56]"
+        );
+        let unicode = format!("{:#}", Matrix::from_fn(40, 1, |i, _| i as i32));
+        assert_eq!(unicode.lines().count(), 17);
+        assert!(unicode.contains("⎢ ⋮⎥"));
+    }
+
+    #[test]
+    fn complex_numbers_are_formatted() {
+        let c = ComplexNumber {
+            real: 1.5,
+            imag: -2.,
+        };
+        assert_eq!(format!("{}", c), "1.5-2i");
+        assert_eq!(format!("{:.1}", c), "1.5-2.0i");
+        let v = Vector::from(&[c, ComplexNumber { real: 0., imag: 1. }]);
+        assert_eq!(format!("{:.1}", v), "[1.5-2.0i]\n[0.0+1.0i]");
+    }
+
+    #[test]
+    fn empty_matrices_display() {
+        assert_eq!(format!("{}", Vector::<i32>::zeros(0)), "[]");
+    }
+}
@@ -0,0 +1,106 @@
+use std::f64::consts::PI;
+
+use num::Float;
+
+use crate::linear_interpolation::Interpolate;
+
+// Maps the interpolation parameter t in [0, 1] onto [0, 1] with the same end
+// points, to change how fast an interpolation moves along its path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Easing {
+    Linear,
+    QuadraticIn,
+    QuadraticOut,
+    QuadraticInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    SmoothStep,
+    SmootherStep,
+}
+
+impl Easing {
+    pub fn apply<T: Float>(self, t: T) -> T {
+        let c = |x: f64| T::from(x).unwrap();
+        let one = T::one();
+        let t = t.max(T::zero()).min(one);
+        match self {
+            Easing::Linear => t,
+            Easing::QuadraticIn => t * t,
+            Easing::QuadraticOut => one - (one - t) * (one - t),
+            Easing::QuadraticInOut => {
+                if t < c(0.5) {
+                    c(2.) * t * t
+                } else {
+                    one - c(2.) * (one - t) * (one - t)
+                }
+            }
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => one - (one - t).powi(3),
+            Easing::CubicInOut => {
+                if t < c(0.5) {
+                    c(4.) * t * t * t
+                } else {
+                    one - c(4.) * (one - t).powi(3)
+                }
+            }
+            Easing::SineIn => one - (t * c(PI / 2.)).cos(),
+            Easing::SineOut => (t * c(PI / 2.)).sin(),
+            Easing::SineInOut => (one - (t * c(PI)).cos()) / c(2.),
+            Easing::SmoothStep => t * t * (c(3.) - c(2.) * t),
+            Easing::SmootherStep => t * t * t * (t * (t * c(6.) - c(15.)) + c(10.)),
+        }
+    }
+
+    pub fn interpolate<P: Interpolate>(self, u: P, v: P, t: P::Scalar) -> P {
+        P::lerp(u, v, self.apply(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [Easing; 12] = [
+        Easing::Linear,
+        Easing::QuadraticIn,
+        Easing::QuadraticOut,
+        Easing::QuadraticInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::SineIn,
+        Easing::SineOut,
+        Easing::SineInOut,
+        Easing::SmoothStep,
+        Easing::SmootherStep,
+    ];
+
+    #[test]
+    fn easings_keep_end_points_and_are_monotonic() {
+        for easing in ALL {
+            assert!(easing.apply(0f64).abs() < 1e-15, "{:?}", easing);
+            assert!((easing.apply(1f64) - 1.).abs() < 1e-15, "{:?}", easing);
+            let samples: Vec<f64> = (0..=100).map(|i| easing.apply(i as f64 / 100.)).collect();
+            assert!(samples.windows(2).all(|w| w[0] <= w[1]), "{:?}", easing);
+        }
+    }
+
+    #[test]
+    fn easings_work() {
+        assert_eq!(Easing::QuadraticIn.apply(0.5), 0.25);
+        assert_eq!(Easing::QuadraticOut.apply(0.5), 0.75);
+        assert_eq!(Easing::CubicInOut.apply(0.25f32), 0.0625);
+        assert_eq!(Easing::SmoothStep.apply(0.5), 0.5);
+        assert_eq!(Easing::SmootherStep.apply(2.), 1.);
+        assert!((Easing::SineInOut.apply(0.5f64) - 0.5).abs() < 1e-15);
+    }
+
+    #[test]
+    fn interpolate_works() {
+        assert_eq!(Easing::QuadraticIn.interpolate(10., 20., 0.5), 12.5);
+    }
+}
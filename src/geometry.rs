@@ -0,0 +1,228 @@
+use num::Float;
+
+use crate::{numeric::Numeric, vector::Vector};
+
+fn check_lengths<K: Numeric>(u: &Vector<K>, v: &Vector<K>) {
+    if u.values.len() != v.values.len() {
+        panic!("Vectors must be the same length");
+    }
+}
+
+fn difference<K: Numeric>(u: &Vector<K>, v: &Vector<K>) -> Vector<K> {
+    check_lengths(u, v);
+    Vector {
+        values: u
+            .values
+            .iter()
+            .zip(v.values.iter())
+            .map(|(&a, &b)| a - b)
+            .collect(),
+    }
+}
+
+impl<K: Numeric> Vector<K> {
+    pub fn cross_2d(&self, other: &Vector<K>) -> K {
+        if self.values.len() != 2 || other.values.len() != 2 {
+            panic!("2D cross product is only defined for vectors of length 2");
+        }
+        self.values[0] * other.values[1] - self.values[1] * other.values[0]
+    }
+
+    // Uses the octonion multiplication table where e_i e_{i+1} = e_{i+3} (indices mod 7).
+    pub fn cross_product_7d(&self, other: &Vector<K>) -> Vector<K> {
+        if self.values.len() != 7 || other.values.len() != 7 {
+            panic!("7D cross product is only defined for vectors of length 7");
+        }
+        let (x, y) = (&self.values, &other.values);
+        let mut result = vec![K::zero(); 7];
+        for i in 0..7 {
+            let (a, b, c) = (i, (i + 1) % 7, (i + 3) % 7);
+            result[c] += x[a] * y[b] - x[b] * y[a];
+            result[a] += x[b] * y[c] - x[c] * y[b];
+            result[b] += x[c] * y[a] - x[a] * y[c];
+        }
+        Vector { values: result }
+    }
+
+    pub fn scalar_triple_product(&self, b: &Vector<K>, c: &Vector<K>) -> K {
+        self.dot(&b.cross_product(c))
+    }
+
+    pub fn vector_triple_product(&self, b: &Vector<K>, c: &Vector<K>) -> Vector<K> {
+        self.cross_product(&b.cross_product(c))
+    }
+}
+
+impl<K: Numeric + Float> Vector<K> {
+    // atan2 of |u x v| and u . v stays accurate for nearly parallel vectors, where
+    // acos of the cosine loses most of its digits. Outside 2D/3D the same is
+    // achieved with Kahan's formula 2 atan2(| |v|u - |u|v |, | |v|u + |u|v |).
+    pub fn angle(&self, other: &Vector<K>) -> K {
+        check_lengths(self, other);
+        match self.values.len() {
+            2 => self.cross_2d(other).abs().atan2(self.dot(other)),
+            3 => self.cross_product(other).norm_2().atan2(self.dot(other)),
+            _ => {
+                let (nu, nv) = (self.norm_2(), other.norm_2());
+                let mut a = self.clone();
+                a.scl(nv);
+                let mut b = other.clone();
+                b.scl(nu);
+                let mut sum = a.clone();
+                sum.add(&b);
+                a.sub(&b);
+                let two = K::one() + K::one();
+                two * a.norm_2().atan2(sum.norm_2())
+            }
+        }
+    }
+
+    pub fn project_onto(&self, onto: &Vector<K>) -> Vector<K> {
+        check_lengths(self, onto);
+        let denominator = onto.dot(onto);
+        if denominator == K::zero() {
+            panic!("Cannot project onto a zero vector");
+        }
+        let mut result = onto.clone();
+        result.scl(self.dot(onto) / denominator);
+        result
+    }
+
+    pub fn reject_from(&self, other: &Vector<K>) -> Vector<K> {
+        difference(self, &self.project_onto(other))
+    }
+
+    pub fn reflect(&self, normal: &Vector<K>) -> Vector<K> {
+        let mut projection = self.project_onto(normal);
+        projection.scl(K::one() + K::one());
+        difference(self, &projection)
+    }
+
+    pub fn distance(&self, other: &Vector<K>) -> K {
+        difference(self, other).norm_2()
+    }
+
+    // A zero vector counts as both parallel and orthogonal to everything.
+    pub fn is_parallel(&self, other: &Vector<K>, tolerance: K) -> bool {
+        check_lengths(self, other);
+        let (nu, nv) = (self.norm_2(), other.norm_2());
+        if nu == K::zero() || nv == K::zero() {
+            return true;
+        }
+        let angle = self.angle(other);
+        angle.sin().abs() <= tolerance
+    }
+
+    pub fn is_orthogonal(&self, other: &Vector<K>, tolerance: K) -> bool {
+        check_lengths(self, other);
+        self.dot(other).abs() <= tolerance * self.norm_2() * other.norm_2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn angle_works() {
+        let v1 = Vector::from(&[1., 0.]);
+        let v2 = Vector::from(&[1., 1.]);
+        assert!((v1.angle(&v2) - std::f64::consts::FRAC_PI_4).abs() < 1e-15);
+        let v3 = Vector::from(&[0., 0., 2.]);
+        let v4 = Vector::from(&[0., -3., 0.]);
+        assert!((v3.angle(&v4) - std::f64::consts::FRAC_PI_2).abs() < 1e-15);
+        let v5 = Vector::from(&[1., 0., 0., 0.]);
+        let v6 = Vector::from(&[-2., 0., 0., 0.]);
+        assert!((v5.angle(&v6) - std::f64::consts::PI).abs() < 1e-15);
+    }
+
+    #[test]
+    fn angle_is_accurate_for_nearly_parallel_vectors() {
+        let v1 = Vector::from(&[1., 0., 0.]);
+        let v2 = Vector::from(&[1., 1e-10, 0.]);
+        assert!((v1.angle(&v2) - 1e-10).abs() < 1e-20);
+        let v3 = Vector::from(&[1., 0., 0., 0.]);
+        let v4 = Vector::from(&[1., 1e-10, 0., 0.]);
+        assert!((v3.angle(&v4) - 1e-10).abs() < 1e-20);
+    }
+
+    #[test]
+    fn project_and_reject_work() {
+        let v = Vector::from(&[3., 4.]);
+        let onto = Vector::from(&[2., 0.]);
+        assert_eq!(v.project_onto(&onto), Vector::from(&[3., 0.]));
+        assert_eq!(v.reject_from(&onto), Vector::from(&[0., 4.]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn project_onto_zero_vector_panics() {
+        Vector::from(&[1., 2.]).project_onto(&Vector::from(&[0., 0.]));
+    }
+
+    #[test]
+    fn reflect_works() {
+        let v = Vector::from(&[1., -1.]);
+        let normal = Vector::from(&[0., 2.]);
+        assert_eq!(v.reflect(&normal), Vector::from(&[1., 1.]));
+    }
+
+    #[test]
+    fn distance_works() {
+        let v1 = Vector::from(&[1., 1.]);
+        let v2 = Vector::from(&[4., 5.]);
+        assert_eq!(v1.distance(&v2), 5.);
+    }
+
+    #[test]
+    fn parallel_and_orthogonal_work() {
+        let v1 = Vector::from(&[1., 2., 3.]);
+        let v2 = Vector::from(&[-2., -4., -6.000001]);
+        assert!(v1.is_parallel(&v2, 1e-6));
+        assert!(!v1.is_parallel(&v2, 1e-9));
+        let v3 = Vector::from(&[3., 0., -1.]);
+        assert!(v1.is_orthogonal(&v3, 1e-12));
+        assert!(!v1.is_orthogonal(&v2, 1e-12));
+    }
+
+    #[test]
+    fn cross_2d_works() {
+        let v1 = Vector::from(&[1, 2]);
+        let v2 = Vector::from(&[3, 4]);
+        assert_eq!(v1.cross_2d(&v2), -2);
+    }
+
+    #[test]
+    fn triple_products_work() {
+        let a = Vector::from(&[1., 2., 3.]);
+        let b = Vector::from(&[0., 1., 4.]);
+        let c = Vector::from(&[5., 6., 0.]);
+        assert_eq!(a.scalar_triple_product(&b, &c), 1.);
+        let expected = {
+            let mut left = b.clone();
+            left.scl(a.dot(&c));
+            let mut right = c.clone();
+            right.scl(a.dot(&b));
+            left.sub(&right);
+            left
+        };
+        assert_eq!(a.vector_triple_product(&b, &c), expected);
+    }
+
+    #[test]
+    fn cross_product_7d_works() {
+        let x = Vector::from(&[1., 2., -1., 0., 3., 1., -2.]);
+        let y = Vector::from(&[0., 1., 2., -3., 1., 4., 1.]);
+        let z = x.cross_product_7d(&y);
+        assert_eq!(z.dot(&x), 0.);
+        assert_eq!(z.dot(&y), 0.);
+        let lagrange = x.dot(&x) * y.dot(&y) - x.dot(&y) * x.dot(&y);
+        assert_eq!(z.dot(&z), lagrange);
+        let e1 = Vector::from(&[1., 0., 0., 0., 0., 0., 0.]);
+        let e2 = Vector::from(&[0., 1., 0., 0., 0., 0., 0.]);
+        assert_eq!(
+            e1.cross_product_7d(&e2),
+            Vector::from(&[0., 0., 0., 1., 0., 0., 0.])
+        );
+    }
+}
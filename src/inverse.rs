@@ -1,56 +1,54 @@
-use crate::{matrix::Matrix, numeric::Numeric};
+use num::Float;
 
-impl<K: Numeric> Matrix<K> {
-    fn augmented_matrix(&self) -> Matrix<K> {
-        let mut augmented = Matrix {
-            values: vec![vec![K::zero(); self.values.len() * 2]; self.values.len()],
-        };
-
-        for i in 0..self.values.len() {
-            for j in 0..self.values.len() {
-                augmented.values[i][j] = self.values[i][j];
-            }
-            augmented.values[i][i + self.values.len()] = K::one();
-        }
-        augmented
-    }
-
-    fn inverse_from_augmented(source: &Matrix<K>, comparison: &Matrix<K>) -> Matrix<K> {
-        let base_length = comparison.values.len();
-        let mut inverse = Matrix {
-            values: vec![vec![K::zero(); base_length]; base_length],
-        };
-        for i in 0..base_length {
-            for j in 0..base_length {
-                inverse.values[i][j] = source.values[i][j + base_length];
-            }
-        }
-        inverse
-    }
+use crate::{condition::unit_roundoff, lu::LuDecomposition, matrix::Matrix, numeric::Numeric};
 
-    pub fn inverse(&self) -> Result<Matrix<K>, String> {
+impl<K: Numeric> Matrix<K> {
+    // Rounding leaves tiny pivots where exact elimination would give zeros, so
+    // for floating point types pivots below n * eps * max |a_ij| count as
+    // singular, as in `rank`.
+    fn invertible_lu(&self) -> Result<LuDecomposition<K>, String> {
         if !self.is_square() {
             return Err("Matrix must be square to have an inverse".to_string());
         }
-        let mut augmented = self.augmented_matrix().reduced_row_echelon();
-        for i in 0..self.values.len() {
-            if augmented.values[i][i] == K::zero() {
+        let lu = self
+            .lu()
+            .map_err(|_| "Matrix is not invertible".to_string())?;
+        let epsilon = unit_roundoff::<K>();
+        if epsilon < 1. {
+            let n = self.values.len();
+            let largest = self
+                .values
+                .iter()
+                .flatten()
+                .fold(0., |acc: f64, x| acc.max(x.magnitude()));
+            let tolerance = n as f64 * epsilon * largest;
+            if (0..n).any(|i| lu.lu.values[i][i].magnitude() <= tolerance) {
                 return Err("Matrix is not invertible".to_string());
             }
         }
-        for i in (0..self.values.len()).rev() {
-            for j in (0..i).rev() {
-                let factor = augmented.values[j][i];
-                augmented.subtract_multiple_of_row(i, j, factor);
-            }
-        }
-        Ok(Self::inverse_from_augmented(&augmented, &self))
+        Ok(lu)
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<K>, String> {
+        Ok(self.invertible_lu()?.inverse())
+    }
+}
+
+impl<K: Numeric + Float> Matrix<K> {
+    // Also returns the estimated 1-norm condition number, taken from the same
+    // LU factorization. `is_ill_conditioned` tells whether the inverse is
+    // likely to be inaccurate.
+    pub fn inverse_with_condition(&self) -> Result<(Matrix<K>, f64), String> {
+        let lu = self.invertible_lu()?;
+        let condition = self.condition_1_estimate_with(&lu);
+        Ok((lu.inverse(), condition))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::linear_interpolation::Interpolate;
 
     #[test]
     fn test_inverse() {
@@ -97,21 +95,23 @@ mod tests {
 
         let inv = m.inverse().unwrap();
 
-        assert_eq!(
-            inv.values,
-            vec![
-                vec![0.6494252873563219, 0.09770114942528735, -0.6551724137931034],
-                vec![
-                    -0.7816091954022988,
-                    -0.12643678160919541,
-                    0.9655172413793103
-                ],
-                vec![
-                    0.14367816091954022,
-                    0.07471264367816091,
-                    -0.2068965517241379
-                ]
-            ]
-        );
+        let expected =
+            Matrix::from(&[&[113., 17., -114.], &[-136., -22., 168.], &[25., 13., -36.]]);
+        crate::assert_relative_eq!(inv, expected.scale(1. / 174.), max_relative = 1e-14);
+    }
+
+    #[test]
+    fn inverse_with_condition_reports_the_estimate() {
+        let m = Matrix::from(&[&[2., 0.], &[0., 0.5]]);
+        let (inv, condition) = m.inverse_with_condition().unwrap();
+        assert_eq!(inv, Matrix::from(&[&[0.5, 0.], &[0., 2.]]));
+        assert_eq!(condition, 4.);
+
+        let hilbert = Matrix::<f64>::hilbert(12);
+        let (_, condition) = hilbert.inverse_with_condition().unwrap();
+        assert!(crate::condition::is_ill_conditioned::<f64>(condition));
+        assert!(Matrix::from(&[&[1., 2.], &[2., 4.]])
+            .inverse_with_condition()
+            .is_err());
     }
 }
@@ -0,0 +1,270 @@
+use std::{
+    fmt::Display,
+    io::{BufRead, Write},
+    str::FromStr,
+};
+
+use super::{format_error, Error};
+use crate::{matrix::Matrix, numeric::Numeric};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    pub has_header: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            has_header: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvTable<K: Numeric> {
+    pub header: Option<Vec<String>>,
+    pub matrix: Matrix<K>,
+}
+
+// Splits one record, honouring double quotes ("" inside quotes is a literal quote).
+fn split_record(line: &str, delimiter: char) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+            }
+            c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        return Err("unterminated quoted field".to_string());
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+// Reads line by line, so only the parsed matrix is held in memory. Blank lines
+// are skipped and every row must have as many fields as the first.
+pub fn read_csv<K: Numeric + FromStr, R: BufRead>(
+    reader: R,
+    options: &CsvOptions,
+) -> Result<CsvTable<K>, Error> {
+    let mut header = None;
+    let mut values: Vec<Vec<K>> = Vec::new();
+    let mut width = None;
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let number = index + 1;
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields = split_record(line, options.delimiter).or_else(|e| format_error(number, e))?;
+        if *width.get_or_insert(fields.len()) != fields.len() {
+            return format_error(
+                number,
+                format!("expected {} fields, found {}", width.unwrap(), fields.len()),
+            );
+        }
+        if options.has_header && header.is_none() {
+            header = Some(fields.iter().map(|f| f.trim().to_string()).collect());
+            continue;
+        }
+        let row = fields
+            .iter()
+            .enumerate()
+            .map(|(column, field)| {
+                field.trim().parse().or_else(|_| {
+                    format_error(
+                        number,
+                        format!("field {}: invalid number `{}`", column + 1, field.trim()),
+                    )
+                })
+            })
+            .collect::<Result<Vec<K>, Error>>()?;
+        values.push(row);
+    }
+    Ok(CsvTable {
+        header,
+        matrix: Matrix { values },
+    })
+}
+
+fn quote(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter) || field.contains('"') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// The header is written exactly when `options.has_header` is set, so that the
+// output reads back with the same options. Records are single lines and
+// `read_csv` trims every field, hence header fields can neither contain line
+// breaks nor start or end with whitespace.
+pub fn write_csv<K: Numeric + Display, W: Write>(
+    mut writer: W,
+    matrix: &Matrix<K>,
+    header: Option<&[&str]>,
+    options: &CsvOptions,
+) -> Result<(), Error> {
+    let separator = options.delimiter.to_string();
+    match header {
+        Some(header) if options.has_header => {
+            if let Some(row) = matrix.values.first() {
+                if header.len() != row.len() {
+                    return Err(Error::Format(format!(
+                        "header has {} fields but the matrix has {} columns",
+                        header.len(),
+                        row.len()
+                    )));
+                }
+            }
+            if let Some(field) = header.iter().find(|h| h.contains(['\n', '\r'])) {
+                return Err(Error::Format(format!(
+                    "header field `{}` contains a line break",
+                    field.escape_default()
+                )));
+            }
+            if let Some(field) = header.iter().find(|h| h.trim() != **h) {
+                return Err(Error::Format(format!(
+                    "header field `{}` has leading or trailing whitespace",
+                    field
+                )));
+            }
+            let fields: Vec<String> = header.iter().map(|h| quote(h, options.delimiter)).collect();
+            writeln!(writer, "{}", fields.join(&separator))?;
+        }
+        None if !options.has_header => {}
+        _ => {
+            return Err(Error::Format(
+                "a header must be given exactly when has_header is set".to_string(),
+            ))
+        }
+    }
+    for row in &matrix.values {
+        let fields: Vec<String> = row.iter().map(|x| x.to_string()).collect();
+        writeln!(writer, "{}", fields.join(&separator))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_csv() {
+        let input = "1, 2.5,3\n\n4,5,-6\r\n";
+        let table: CsvTable<f64> = read_csv(input.as_bytes(), &CsvOptions::default()).unwrap();
+        assert_eq!(table.header, None);
+        assert_eq!(
+            table.matrix,
+            Matrix::from(&[&[1., 2.5, 3.], &[4., 5., -6.]])
+        );
+    }
+
+    #[test]
+    fn reads_headers_and_delimiters() {
+        let input = "x;\"y; \"\"scaled\"\"\"\n1;2\n";
+        let options = CsvOptions {
+            delimiter: ';',
+            has_header: true,
+        };
+        let table: CsvTable<i32> = read_csv(input.as_bytes(), &options).unwrap();
+        assert_eq!(
+            table.header,
+            Some(vec!["x".to_string(), "y; \"scaled\"".to_string()])
+        );
+        assert_eq!(table.matrix, Matrix::from(&[&[1, 2]]));
+    }
+
+    #[test]
+    fn reports_bad_rows() {
+        let ragged = read_csv::<f64, _>("1,2\n3\n".as_bytes(), &CsvOptions::default());
+        assert_eq!(
+            ragged.unwrap_err().to_string(),
+            "line 2: expected 2 fields, found 1"
+        );
+        let invalid = read_csv::<f64, _>("1,2\n3,abc\n".as_bytes(), &CsvOptions::default());
+        assert_eq!(
+            invalid.unwrap_err().to_string(),
+            "line 2: field 2: invalid number `abc`"
+        );
+    }
+
+    #[test]
+    fn write_read_round_trip() {
+        let m = Matrix::from(&[&[1.5, -2.], &[1e-300, 4.]]);
+        let options = CsvOptions {
+            delimiter: ';',
+            has_header: true,
+        };
+        let mut buffer = Vec::new();
+        write_csv(&mut buffer, &m, Some(&["a", "b;c"]), &options).unwrap();
+        assert_eq!(
+            String::from_utf8(buffer.clone()).unwrap().lines().next(),
+            Some("a;\"b;c\"")
+        );
+        let table: CsvTable<f64> = read_csv(buffer.as_slice(), &options).unwrap();
+        assert_eq!(table.header, Some(vec!["a".to_string(), "b;c".to_string()]));
+        assert_eq!(table.matrix, m);
+
+        let mut buffer = Vec::new();
+        write_csv(&mut buffer, &m, None, &CsvOptions::default()).unwrap();
+        let table: CsvTable<f64> = read_csv(buffer.as_slice(), &CsvOptions::default()).unwrap();
+        assert_eq!(table.matrix, m);
+    }
+
+    #[test]
+    fn rejects_headers_that_would_not_read_back() {
+        let m = Matrix::from(&[&[1, 2]]);
+        let options = CsvOptions {
+            has_header: true,
+            ..CsvOptions::default()
+        };
+        let error = |header: Option<&[&str]>, options: &CsvOptions| {
+            write_csv(Vec::new(), &m, header, options)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error(Some(&["a", "b", "c"]), &options),
+            "header has 3 fields but the matrix has 2 columns"
+        );
+        assert_eq!(
+            error(Some(&["a", "b\nc"]), &options),
+            "header field `b\\nc` contains a line break"
+        );
+        assert_eq!(
+            error(Some(&[" a", "b"]), &options),
+            "header field ` a` has leading or trailing whitespace"
+        );
+        assert_eq!(
+            error(Some(&["a", "b\t"]), &options),
+            "header field `b\t` has leading or trailing whitespace"
+        );
+        assert_eq!(
+            error(None, &options),
+            "a header must be given exactly when has_header is set"
+        );
+        assert_eq!(
+            error(Some(&["a", "b"]), &CsvOptions::default()),
+            "a header must be given exactly when has_header is set"
+        );
+    }
+}
@@ -0,0 +1,480 @@
+use std::{
+    fmt::Display,
+    io::{BufRead, Lines, Write},
+    str::FromStr,
+};
+
+use super::{format_error, Error};
+use crate::{
+    matrix::Matrix,
+    numeric::Numeric,
+    sparse::{CooMatrix, CsrMatrix},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Format {
+    Coordinate,
+    Array,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Field {
+    Real,
+    Integer,
+    Pattern,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+// Reads the banner and size line, then hands out entries one line at a time so
+// that large files never have to be held in memory as text.
+struct Reader<R: BufRead> {
+    lines: Lines<R>,
+    line: usize,
+    format: Format,
+    field: Field,
+    symmetry: Symmetry,
+    rows: usize,
+    cols: usize,
+    entries: usize,
+}
+
+fn parse_banner(banner: &str) -> Result<(Format, Field, Symmetry), String> {
+    let words: Vec<String> = banner.split_whitespace().map(str::to_lowercase).collect();
+    if words.len() != 5 || words[0] != "%%matrixmarket" || words[1] != "matrix" {
+        return Err("expected `%%MatrixMarket matrix <format> <field> <symmetry>`".to_string());
+    }
+    let format = match words[2].as_str() {
+        "coordinate" => Format::Coordinate,
+        "array" => Format::Array,
+        other => return Err(format!("unknown format `{}`", other)),
+    };
+    let field = match words[3].as_str() {
+        "real" | "double" => Field::Real,
+        "integer" => Field::Integer,
+        "pattern" if format == Format::Coordinate => Field::Pattern,
+        "pattern" => return Err("pattern matrices must use the coordinate format".to_string()),
+        "complex" => return Err("complex matrices are not supported".to_string()),
+        other => return Err(format!("unknown field `{}`", other)),
+    };
+    let symmetry = match words[4].as_str() {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" if field == Field::Pattern => {
+            return Err("pattern matrices cannot be skew-symmetric".to_string())
+        }
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        "hermitian" => return Err("hermitian matrices are not supported".to_string()),
+        other => return Err(format!("unknown symmetry `{}`", other)),
+    };
+    Ok((format, field, symmetry))
+}
+
+fn parse_number<T: FromStr>(line: usize, word: &str) -> Result<T, Error> {
+    word.parse()
+        .or_else(|_| format_error(line, format!("invalid number `{}`", word)))
+}
+
+fn too_large<T>(line: usize, rows: usize, cols: usize) -> Result<T, Error> {
+    format_error(line, format!("a {}x{} matrix is too large", rows, cols))
+}
+
+impl<R: BufRead> Reader<R> {
+    fn new(reader: R) -> Result<Self, Error> {
+        let mut lines = reader.lines();
+        let banner = lines
+            .next()
+            .transpose()?
+            .ok_or_else(|| Error::Format("line 1: file is empty".to_string()))?;
+        let (format, field, symmetry) = parse_banner(&banner).or_else(|e| format_error(1, e))?;
+        let mut reader = Reader {
+            lines,
+            line: 1,
+            format,
+            field,
+            symmetry,
+            rows: 0,
+            cols: 0,
+            entries: 0,
+        };
+        let size = reader
+            .next_line()?
+            .ok_or_else(|| Error::Format("missing size line".to_string()))?;
+        let expected = if format == Format::Coordinate { 3 } else { 2 };
+        if size.len() != expected {
+            return format_error(
+                reader.line,
+                format!("expected {} sizes, found {}", expected, size.len()),
+            );
+        }
+        reader.rows = parse_number(reader.line, &size[0])?;
+        reader.cols = parse_number(reader.line, &size[1])?;
+        if symmetry != Symmetry::General && reader.rows != reader.cols {
+            return format_error(
+                reader.line,
+                format!(
+                    "a {}x{} matrix cannot be symmetric",
+                    reader.rows, reader.cols
+                ),
+            );
+        }
+        let (rows, cols) = (reader.rows, reader.cols);
+        let entries = match format {
+            Format::Coordinate => Some(parse_number(reader.line, &size[2])?),
+            Format::Array => match symmetry {
+                Symmetry::General => rows.checked_mul(cols),
+                Symmetry::Symmetric => rows
+                    .checked_add(1)
+                    .and_then(|n| n.checked_mul(rows))
+                    .map(|n| n / 2),
+                Symmetry::SkewSymmetric => rows.checked_mul(rows.saturating_sub(1)).map(|n| n / 2),
+            },
+        };
+        reader.entries = match entries {
+            Some(entries) => entries,
+            None => return too_large(reader.line, rows, cols),
+        };
+        Ok(reader)
+    }
+
+    // The next non-comment, non-blank line split into words.
+    fn next_line(&mut self) -> Result<Option<Vec<String>>, Error> {
+        for line in self.lines.by_ref() {
+            let line = line?;
+            self.line += 1;
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('%') {
+                continue;
+            }
+            return Ok(Some(
+                trimmed.split_whitespace().map(str::to_string).collect(),
+            ));
+        }
+        Ok(None)
+    }
+
+    // Calls `put` for every stored entry and its mirror image, if any.
+    fn read_entries<K, F>(mut self, mut put: F) -> Result<(), Error>
+    where
+        K: Numeric + FromStr,
+        F: FnMut(usize, usize, K),
+    {
+        // Array data is column-major and symmetric arrays only store the lower
+        // triangle (strictly lower when skew-symmetric).
+        let (mut i, mut j) = match self.symmetry {
+            Symmetry::SkewSymmetric => (1, 0),
+            _ => (0, 0),
+        };
+        for _ in 0..self.entries {
+            let words = match self.next_line()? {
+                Some(words) => words,
+                None => {
+                    return format_error(
+                        self.line,
+                        format!("expected {} entries, file ended early", self.entries),
+                    )
+                }
+            };
+            let expected = match (self.format, self.field) {
+                (Format::Array, _) => 1,
+                (Format::Coordinate, Field::Pattern) => 2,
+                (Format::Coordinate, _) => 3,
+            };
+            if words.len() != expected {
+                return format_error(
+                    self.line,
+                    format!("expected {} values, found {}", expected, words.len()),
+                );
+            }
+            let value = match self.field {
+                Field::Pattern => K::one(),
+                _ => parse_number(self.line, &words[expected - 1])?,
+            };
+            let (row, col) = match self.format {
+                Format::Array => (i, j),
+                Format::Coordinate => {
+                    let row: usize = parse_number(self.line, &words[0])?;
+                    let col: usize = parse_number(self.line, &words[1])?;
+                    if row == 0 || row > self.rows || col == 0 || col > self.cols {
+                        return format_error(
+                            self.line,
+                            format!(
+                                "entry ({}, {}) is outside the {}x{} matrix",
+                                row, col, self.rows, self.cols
+                            ),
+                        );
+                    }
+                    (row - 1, col - 1)
+                }
+            };
+            match self.symmetry {
+                Symmetry::General => put(row, col, value),
+                _ if row < col => {
+                    return format_error(
+                        self.line,
+                        format!(
+                            "entry ({}, {}) is above the diagonal of a symmetric matrix",
+                            row + 1,
+                            col + 1
+                        ),
+                    )
+                }
+                Symmetry::SkewSymmetric if row == col => {
+                    return format_error(
+                        self.line,
+                        "skew-symmetric matrices have no diagonal entries".to_string(),
+                    )
+                }
+                Symmetry::Symmetric => {
+                    put(row, col, value);
+                    if row != col {
+                        put(col, row, value);
+                    }
+                }
+                Symmetry::SkewSymmetric => {
+                    put(row, col, value);
+                    put(col, row, K::zero() - value);
+                }
+            }
+            i += 1;
+            if i == self.rows {
+                j += 1;
+                i = match self.symmetry {
+                    Symmetry::General => 0,
+                    Symmetry::Symmetric => j,
+                    Symmetry::SkewSymmetric => j + 1,
+                };
+            }
+        }
+        if self.next_line()?.is_some() {
+            return format_error(
+                self.line,
+                format!("expected {} entries, found more", self.entries),
+            );
+        }
+        Ok(())
+    }
+}
+
+pub fn read_matrix_market<K: Numeric + FromStr, R: BufRead>(reader: R) -> Result<Matrix<K>, Error> {
+    let reader = Reader::new(reader)?;
+    let (rows, cols, line) = (reader.rows, reader.cols, reader.line);
+    if rows.checked_mul(cols).is_none() {
+        return too_large(line, rows, cols);
+    }
+    // The entries are collected before the matrix is allocated, so that a
+    // header claiming a huge size fails on the missing data instead. A valid
+    // coordinate file may still ask for more than can be allocated, so the
+    // rows are reserved fallibly.
+    let mut entries = Vec::new();
+    reader.read_entries(|i, j, value: K| entries.push((i, j, value)))?;
+    let mut values = Vec::new();
+    values
+        .try_reserve_exact(rows)
+        .or_else(|_| too_large(line, rows, cols))?;
+    for _ in 0..rows {
+        let mut row = Vec::new();
+        row.try_reserve_exact(cols)
+            .or_else(|_| too_large(line, rows, cols))?;
+        row.resize(cols, K::zero());
+        values.push(row);
+    }
+    let mut matrix = Matrix { values };
+    // Duplicate coordinate entries are summed, as in CooMatrix::to_dense.
+    for (i, j, value) in entries {
+        matrix.values[i][j] += value;
+    }
+    Ok(matrix)
+}
+
+pub fn read_matrix_market_sparse<K: Numeric + FromStr, R: BufRead>(
+    reader: R,
+) -> Result<CooMatrix<K>, Error> {
+    let reader = Reader::new(reader)?;
+    let mut matrix = CooMatrix::new(reader.rows, reader.cols);
+    reader.read_entries(|i, j, value: K| {
+        if value != K::zero() {
+            matrix.push(i, j, value)
+        }
+    })?;
+    Ok(matrix)
+}
+
+// Integers are valid `real` entries, so every matrix is written as real.
+pub fn write_matrix_market<K: Numeric + Display, W: Write>(
+    mut writer: W,
+    matrix: &Matrix<K>,
+) -> Result<(), Error> {
+    let rows = matrix.values.len();
+    let cols = matrix.values.first().map_or(0, |row| row.len());
+    writeln!(writer, "%%MatrixMarket matrix array real general")?;
+    writeln!(writer, "{} {}", rows, cols)?;
+    for j in 0..cols {
+        for row in &matrix.values {
+            writeln!(writer, "{}", row[j])?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn write_matrix_market_sparse<K: Numeric + Display, W: Write>(
+    mut writer: W,
+    matrix: &CsrMatrix<K>,
+) -> Result<(), Error> {
+    writeln!(writer, "%%MatrixMarket matrix coordinate real general")?;
+    writeln!(
+        writer,
+        "{} {} {}",
+        matrix.rows(),
+        matrix.cols(),
+        matrix.nnz()
+    )?;
+    for i in 0..matrix.rows() {
+        for (j, value) in matrix.row(i) {
+            writeln!(writer, "{} {} {}", i + 1, j + 1, value)?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_coordinate_files() {
+        let input = "%%MatrixMarket matrix coordinate real general\n% comment\n\n2 3 3\n1 1 1.5\n2 3 -2\n1 1 0.5\n";
+        let m: Matrix<f64> = read_matrix_market(input.as_bytes()).unwrap();
+        assert_eq!(m, Matrix::from(&[&[2., 0., 0.], &[0., 0., -2.]]));
+        let sparse: CooMatrix<f64> = read_matrix_market_sparse(input.as_bytes()).unwrap();
+        assert_eq!(sparse.nnz(), 3);
+        assert_eq!(sparse.to_csr().to_dense(), m);
+    }
+
+    #[test]
+    fn reads_symmetric_and_pattern_files() {
+        let symmetric = "%%MatrixMarket matrix coordinate integer symmetric\n2 2 2\n1 1 4\n2 1 7\n";
+        let m: Matrix<i32> = read_matrix_market(symmetric.as_bytes()).unwrap();
+        assert_eq!(m, Matrix::from(&[&[4, 7], &[7, 0]]));
+
+        let pattern = "%%MatrixMarket matrix coordinate pattern general\n2 2 2\n1 2\n2 1\n";
+        let m: Matrix<f32> = read_matrix_market(pattern.as_bytes()).unwrap();
+        assert_eq!(m, Matrix::from(&[&[0., 1.], &[1., 0.]]));
+
+        let skew = "%%MatrixMarket matrix coordinate real skew-symmetric\n2 2 1\n2 1 3\n";
+        let m: Matrix<f64> = read_matrix_market(skew.as_bytes()).unwrap();
+        assert_eq!(m, Matrix::from(&[&[0., -3.], &[3., 0.]]));
+    }
+
+    #[test]
+    fn reads_array_files_in_column_major_order() {
+        let general = "%%MatrixMarket matrix array integer general\n2 3\n1\n4\n2\n5\n3\n6\n";
+        let m: Matrix<i32> = read_matrix_market(general.as_bytes()).unwrap();
+        assert_eq!(m, Matrix::from(&[&[1, 2, 3], &[4, 5, 6]]));
+
+        let symmetric = "%%MatrixMarket matrix array real symmetric\n3 3\n1\n2\n3\n4\n5\n6\n";
+        let m: Matrix<f64> = read_matrix_market(symmetric.as_bytes()).unwrap();
+        assert_eq!(
+            m,
+            Matrix::from(&[&[1., 2., 3.], &[2., 4., 5.], &[3., 5., 6.]])
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        let error = |input: &str| {
+            read_matrix_market::<f64, _>(input.as_bytes())
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("%%MatrixMarket matrix coordinate complex general\n1 1 0\n"),
+            "line 1: complex matrices are not supported"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1\n"),
+            "line 3: entry (3, 1) is outside the 2x2 matrix"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n"),
+            "line 3: expected 2 entries, file ended early"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix coordinate real symmetric\n2 2 1\n1 2 1\n"),
+            "line 3: entry (1, 2) is above the diagonal of a symmetric matrix"
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix array real general\n1 1\n1\n2\n"),
+            "line 4: expected 1 entries, found more"
+        );
+    }
+
+    #[test]
+    fn huge_sizes_fail_without_allocating() {
+        let error = |input: String| {
+            read_matrix_market::<f64, _>(input.as_bytes())
+                .unwrap_err()
+                .to_string()
+        };
+        let max = usize::MAX;
+        assert_eq!(
+            error(format!(
+                "%%MatrixMarket matrix array real general\n{} 2\n",
+                max
+            )),
+            format!("line 2: a {}x2 matrix is too large", max)
+        );
+        assert_eq!(
+            error(format!(
+                "%%MatrixMarket matrix array real symmetric\n{} {}\n",
+                max, max
+            )),
+            format!("line 2: a {}x{} matrix is too large", max, max)
+        );
+        assert_eq!(
+            error(format!(
+                "%%MatrixMarket matrix coordinate real general\n{} 2 0\n",
+                max
+            )),
+            format!("line 2: a {}x2 matrix is too large", max)
+        );
+        assert_eq!(
+            error("%%MatrixMarket matrix array real general\n1000000000 1000000\n1\n".to_string()),
+            "line 3: expected 1000000000000000 entries, file ended early"
+        );
+        assert_eq!(
+            error(
+                "%%MatrixMarket matrix coordinate real general\n1 1152921504606846976 0\n"
+                    .to_string()
+            ),
+            "line 2: a 1x1152921504606846976 matrix is too large"
+        );
+        let sparse = format!(
+            "%%MatrixMarket matrix coordinate real general\n{} 2 1\n{} 2 1\n",
+            max, max
+        );
+        let sparse: CooMatrix<f64> = read_matrix_market_sparse(sparse.as_bytes()).unwrap();
+        assert_eq!(sparse.nnz(), 1);
+    }
+
+    #[test]
+    fn write_read_round_trip() {
+        let m = Matrix::from(&[&[1.5, 0., -3.], &[0., 2.25, 0.]]);
+        let mut buffer = Vec::new();
+        write_matrix_market(&mut buffer, &m).unwrap();
+        assert_eq!(read_matrix_market::<f64, _>(buffer.as_slice()).unwrap(), m);
+
+        let mut buffer = Vec::new();
+        write_matrix_market_sparse(&mut buffer, &CsrMatrix::from_dense(&m)).unwrap();
+        assert!(buffer.starts_with(b"%%MatrixMarket matrix coordinate real general\n2 3 3\n"));
+        let sparse: CooMatrix<f64> = read_matrix_market_sparse(buffer.as_slice()).unwrap();
+        assert_eq!(sparse.to_dense(), m);
+    }
+}
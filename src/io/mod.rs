@@ -0,0 +1,46 @@
+mod csv;
+mod matrix_market;
+mod npy;
+
+pub use csv::{read_csv, write_csv, CsvOptions, CsvTable};
+pub use matrix_market::{
+    read_matrix_market, read_matrix_market_sparse, write_matrix_market, write_matrix_market_sparse,
+};
+pub use npy::{read_npy, write_npy, NpyElement};
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    // Malformed input; the message says where when the format has lines.
+    Format(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{}", error),
+            Error::Format(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            Error::Format(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+fn format_error<T>(line: usize, message: String) -> Result<T, Error> {
+    Err(Error::Format(format!("line {}: {}", line, message)))
+}
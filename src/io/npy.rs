@@ -0,0 +1,296 @@
+use std::io::{Read, Write};
+
+use super::Error;
+use crate::{matrix::Matrix, numeric::Numeric};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+// Element types that map onto a NumPy dtype. `TYPE` is the dtype without its
+// byte-order character, e.g. "f8" for `<f8`.
+pub trait NpyElement: Numeric {
+    const TYPE: &'static str;
+    const SIZE: usize;
+
+    fn from_bytes(bytes: &[u8], little_endian: bool) -> Self;
+    fn write_le(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_element {
+    ($($t:ty => $type:literal),*) => {$(
+        impl NpyElement for $t {
+            const TYPE: &'static str = $type;
+            const SIZE: usize = std::mem::size_of::<$t>();
+
+            fn from_bytes(bytes: &[u8], little_endian: bool) -> Self {
+                let bytes = bytes.try_into().unwrap();
+                if little_endian {
+                    <$t>::from_le_bytes(bytes)
+                } else {
+                    <$t>::from_be_bytes(bytes)
+                }
+            }
+
+            fn write_le(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    )*};
+}
+
+impl_npy_element!(f32 => "f4", f64 => "f8", i8 => "i1", i16 => "i2", i32 => "i4");
+
+fn format_error<T>(message: String) -> Result<T, Error> {
+    Err(Error::Format(format!("npy: {}", message)))
+}
+
+// The raw text of `'key': value` in the header dictionary. Strings keep their
+// quotes stripped and tuples keep their parentheses.
+fn dict_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let start = header
+        .find(&format!("'{}'", key))
+        .or_else(|| header.find(&format!("\"{}\"", key)))?;
+    let rest = header[start + key.len() + 2..]
+        .trim_start()
+        .strip_prefix(':')?;
+    let rest = rest.trim_start();
+    let end = match rest.chars().next()? {
+        quote @ ('\'' | '"') => return rest[1..].find(quote).map(|end| &rest[1..end + 1]),
+        '(' => rest.find(')')? + 1,
+        _ => rest.find([',', '}']).unwrap_or(rest.len()),
+    };
+    Some(rest[..end].trim())
+}
+
+fn parse_shape(shape: &str) -> Option<Vec<usize>> {
+    shape
+        .strip_prefix('(')?
+        .strip_suffix(')')?
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse().ok())
+        .collect()
+}
+
+// Reads exactly `len` bytes. The buffer grows as the data arrives instead of
+// being sized from the (untrusted) header up front.
+fn read_bytes<R: Read>(reader: &mut R, len: usize) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != len {
+        return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+    }
+    Ok(bytes)
+}
+
+// Reads a 2-D array in either byte order and either memory order. The dtype
+// must match `K` exactly; no conversions are done.
+pub fn read_npy<K: NpyElement, R: Read>(mut reader: R) -> Result<Matrix<K>, Error> {
+    let mut preamble = [0u8; 8];
+    reader.read_exact(&mut preamble)?;
+    if &preamble[..6] != MAGIC {
+        return format_error("missing magic string".to_string());
+    }
+    let header_len = match preamble[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            reader.read_exact(&mut len)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            reader.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        major => return format_error(format!("unsupported version {}", major)),
+    };
+    let header = String::from_utf8(read_bytes(&mut reader, header_len)?)
+        .or_else(|_| format_error("header is not valid text".to_string()))?;
+
+    let descr = dict_value(&header, "descr")
+        .ok_or_else(|| Error::Format("npy: header has no descr".to_string()))?;
+    let (order, dtype) = descr.split_at(descr.len().min(1));
+    let little_endian = match order {
+        "<" => true,
+        ">" => false,
+        "=" => cfg!(target_endian = "little"),
+        "|" if K::SIZE == 1 => true,
+        _ => return format_error(format!("unsupported dtype '{}'", descr)),
+    };
+    if dtype != K::TYPE {
+        return format_error(format!(
+            "dtype '{}' does not match the expected '{}'",
+            descr,
+            K::TYPE
+        ));
+    }
+    let fortran_order = match dict_value(&header, "fortran_order") {
+        Some("False") => false,
+        Some("True") => true,
+        _ => return format_error("header has no valid fortran_order".to_string()),
+    };
+    let shape = dict_value(&header, "shape")
+        .and_then(parse_shape)
+        .ok_or_else(|| Error::Format("npy: header has no valid shape".to_string()))?;
+    let (rows, cols) = match shape[..] {
+        [rows, cols] => (rows, cols),
+        _ => return format_error(format!("expected a 2-D array, found shape {:?}", shape)),
+    };
+
+    let len = rows
+        .checked_mul(cols)
+        .and_then(|count| count.checked_mul(K::SIZE))
+        .ok_or_else(|| Error::Format(format!("npy: shape ({}, {}) is too large", rows, cols)))?;
+    let data = read_bytes(&mut reader, len)?;
+    let element = |index: usize| K::from_bytes(&data[index * K::SIZE..][..K::SIZE], little_endian);
+    let mut values = Vec::new();
+    values
+        .try_reserve_exact(rows)
+        .or_else(|_| format_error(format!("shape ({}, {}) is too large", rows, cols)))?;
+    values.extend((0..rows).map(|i| {
+        (0..cols)
+            .map(|j| {
+                element(if fortran_order {
+                    j * rows + i
+                } else {
+                    i * cols + j
+                })
+            })
+            .collect()
+    }));
+    Ok(Matrix { values })
+}
+
+// Writes version 1.0, little-endian and in C order, padding the header so the
+// data starts on a 64-byte boundary.
+pub fn write_npy<K: NpyElement, W: Write>(mut writer: W, matrix: &Matrix<K>) -> Result<(), Error> {
+    let rows = matrix.values.len();
+    let cols = matrix.values.first().map_or(0, |row| row.len());
+    let order = if K::SIZE == 1 { '|' } else { '<' };
+    let mut header = format!(
+        "{{'descr': '{}{}', 'fortran_order': False, 'shape': ({}, {}), }}",
+        order,
+        K::TYPE,
+        rows,
+        cols
+    );
+    let unpadded = MAGIC.len() + 4 + header.len() + 1;
+    header.push_str(&" ".repeat(unpadded.next_multiple_of(64) - unpadded));
+    header.push('\n');
+    let header_len =
+        u16::try_from(header.len()).or_else(|_| format_error("header is too long".to_string()))?;
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[1, 0])?;
+    writer.write_all(&header_len.to_le_bytes())?;
+    writer.write_all(header.as_bytes())?;
+    let mut buffer = Vec::with_capacity(cols * K::SIZE);
+    for row in &matrix.values {
+        buffer.clear();
+        row.iter().for_each(|&value| value.write_le(&mut buffer));
+        writer.write_all(&buffer)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn npy(descr: &str, fortran_order: bool, shape: &str, data: &[u8]) -> Vec<u8> {
+        let header = format!(
+            "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}\n",
+            descr,
+            if fortran_order { "True" } else { "False" },
+            shape
+        );
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    #[test]
+    fn write_read_round_trip() {
+        let m = Matrix::from(&[&[1.5, -2., 3.], &[4., 5., f64::MAX]]);
+        let mut buffer = Vec::new();
+        write_npy(&mut buffer, &m).unwrap();
+        let data_start = 10 + u16::from_le_bytes([buffer[8], buffer[9]]) as usize;
+        assert_eq!(data_start % 64, 0);
+        assert_eq!(buffer[data_start - 1], b'\n');
+        assert_eq!(buffer.len(), data_start + 6 * 8);
+        assert_eq!(read_npy::<f64, _>(buffer.as_slice()).unwrap(), m);
+
+        let m = Matrix::from(&[&[-1i8, 2], &[3, 4]]);
+        let mut buffer = Vec::new();
+        write_npy(&mut buffer, &m).unwrap();
+        assert!(String::from_utf8_lossy(&buffer).contains("'descr': '|i1'"));
+        assert_eq!(read_npy::<i8, _>(buffer.as_slice()).unwrap(), m);
+    }
+
+    #[test]
+    fn reads_big_endian_and_fortran_order() {
+        let data: Vec<u8> = [1i16, 3, 2, 4]
+            .iter()
+            .flat_map(|x| x.to_be_bytes())
+            .collect();
+        let bytes = npy(">i2", true, "(2, 2)", &data);
+        assert_eq!(
+            read_npy::<i16, _>(bytes.as_slice()).unwrap(),
+            Matrix::from(&[&[1, 2], &[3, 4]])
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_files() {
+        let error = |bytes: Vec<u8>| {
+            read_npy::<f32, _>(bytes.as_slice())
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error(npy("<f8", false, "(1, 1)", &[0; 8])),
+            "npy: dtype '<f8' does not match the expected 'f4'"
+        );
+        assert_eq!(
+            error(npy("<f4", false, "(4,)", &[0; 16])),
+            "npy: expected a 2-D array, found shape [4]"
+        );
+        assert_eq!(
+            error(b"PK\x03\x04 not npy".to_vec()),
+            "npy: missing magic string"
+        );
+        assert!(matches!(
+            read_npy::<f32, _>(npy("<f4", false, "(2, 2)", &[0; 12]).as_slice()),
+            Err(Error::Io(_))
+        ));
+    }
+
+    #[test]
+    fn huge_shapes_fail_without_allocating() {
+        let shape = format!("({}, {})", usize::MAX, 2);
+        assert_eq!(
+            read_npy::<f32, _>(npy("<f4", false, &shape, &[]).as_slice())
+                .unwrap_err()
+                .to_string(),
+            format!("npy: shape ({}, 2) is too large", usize::MAX)
+        );
+        for fortran_order in [false, true] {
+            let truncated = npy("<f8", fortran_order, "(1000000000, 1000000)", &[0; 64]);
+            assert!(matches!(
+                read_npy::<f64, _>(truncated.as_slice()),
+                Err(Error::Io(_))
+            ));
+        }
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[2, 0]);
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            read_npy::<f32, _>(bytes.as_slice()),
+            Err(Error::Io(_))
+        ));
+    }
+}
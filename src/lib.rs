@@ -0,0 +1,43 @@
+pub mod approx;
+pub mod basis;
+pub mod complex_number;
+pub mod condition;
+pub mod constructors;
+pub mod cos;
+pub mod cross_product;
+pub mod determinant;
+pub mod display;
+pub mod easing;
+pub mod geometry;
+pub mod inverse;
+pub mod io;
+pub mod linear_combinations;
+pub mod linear_interpolation;
+pub mod linear_operator;
+pub mod lu;
+pub mod matrix;
+pub mod matrix_functions;
+pub mod matrix_multiplication;
+pub mod norm;
+pub mod numeric;
+pub mod parse;
+#[cfg(test)]
+mod property_tests;
+pub mod quaternion;
+#[cfg(any(test, feature = "rand"))]
+pub mod random;
+pub mod rank;
+pub mod row_echelon_form;
+#[cfg(feature = "serde")]
+mod serialization;
+mod simd;
+pub mod solvers;
+pub mod sparse;
+pub mod spline;
+pub mod strassen;
+pub mod structured;
+pub mod summation;
+pub mod trace;
+pub mod transform;
+pub mod transpose;
+pub mod vector;
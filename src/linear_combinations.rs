@@ -1,9 +1,34 @@
-use crate::{numeric::Numeric, vector::Vector};
+use crate::{
+    numeric::Numeric,
+    summation::{self, summation_policy, Summation},
+    vector::Vector,
+};
 
 pub fn linear_combination<K: Numeric>(u: &[Vector<K>], coefs: &[K]) -> Vector<K> {
+    linear_combination_with(u, coefs, summation_policy())
+}
+
+pub fn linear_combination_with<K: Numeric>(
+    u: &[Vector<K>],
+    coefs: &[K],
+    method: Summation,
+) -> Vector<K> {
     if u.len() != coefs.len() {
         panic!("Need same number of vectors and coefficients");
     }
+    if method != Summation::Naive {
+        let len = u[0].values.len();
+        if u.iter().any(|v| v.values.len() != len) {
+            panic!("Vectors must be the same length");
+        }
+        let values = (0..len)
+            .map(|j| {
+                let column: Vec<K> = u.iter().map(|v| v.values[j]).collect();
+                summation::dot(&column, coefs, method)
+            })
+            .collect();
+        return Vector { values };
+    }
     u.iter()
         .zip(coefs.iter())
         .map(|(v, c)| {
@@ -65,6 +90,23 @@ mod tests {
         assert_eq!(result.values, vec![10., 0., 230.]);
     }
 
+    #[test]
+    fn compensated_linear_combination_keeps_small_contributions() {
+        let v1 = Vector::from(&[1e16, 1.]);
+        let v2 = Vector::from(&[1., 0.]);
+        let v3 = Vector::from(&[-1e16, 0.]);
+
+        let naive = linear_combination_with(
+            &[v1.clone(), v2.clone(), v3.clone()],
+            &[1., 1., 1.],
+            Summation::Naive,
+        );
+        let exact = linear_combination_with(&[v1, v2, v3], &[1., 1., 1.], Summation::Exact);
+
+        assert_eq!(naive.values, vec![0., 1.]);
+        assert_eq!(exact.values, vec![1., 1.]);
+    }
+
     #[test]
     #[should_panic]
     fn linear_combination_panics_if_not_enough_scalars() {
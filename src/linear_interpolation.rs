@@ -1,24 +1,76 @@
-use std::ops::{Add, Mul, Sub};
+use num::{Float, One};
 
-use crate::numeric::Numeric;
+use crate::{matrix::Matrix, numeric::Numeric, vector::Vector};
 
-trait Lerp<V: Numeric> {
-    fn lerp(u: V, v: V, t: f32) -> V;
+// Anything that can be blended as an affine combination. `scale` and
+// `add_scaled` are all the higher-order schemes in `spline` need.
+pub trait Interpolate: Clone {
+    type Scalar: Numeric + Float;
+
+    fn scale(&self, s: Self::Scalar) -> Self;
+
+    // self + other * s
+    fn add_scaled(&self, other: &Self, s: Self::Scalar) -> Self;
+
+    fn lerp(u: Self, v: Self, t: Self::Scalar) -> Self {
+        u.scale(Self::Scalar::one() - t).add_scaled(&v, t)
+    }
 }
 
-impl<T> Lerp<T> for T
-where
-    T: Numeric + Copy + Add<Output = T> + Mul<f32, Output = T> + Sub<Output = T>,
-{
-    fn lerp(u: T, v: T, t: f32) -> T {
-        u * (1.0 - t) + v * t
+macro_rules! impl_interpolate_for_float {
+    ($($t:ty),*) => {
+        $(
+            impl Interpolate for $t {
+                type Scalar = $t;
+
+                fn scale(&self, s: $t) -> $t {
+                    self * s
+                }
+
+                fn add_scaled(&self, other: &$t, s: $t) -> $t {
+                    self + other * s
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_for_float!(f32, f64);
+
+impl<K: Numeric + Float> Interpolate for Vector<K> {
+    type Scalar = K;
+
+    fn scale(&self, s: K) -> Vector<K> {
+        let mut result = self.clone();
+        result.scl(s);
+        result
+    }
+
+    fn add_scaled(&self, other: &Vector<K>, s: K) -> Vector<K> {
+        let mut result = self.clone();
+        result.add(&other.scale(s));
+        result
+    }
+}
+
+impl<K: Numeric + Float> Interpolate for Matrix<K> {
+    type Scalar = K;
+
+    fn scale(&self, s: K) -> Matrix<K> {
+        let mut result = self.clone();
+        result.scl(s);
+        result
+    }
+
+    fn add_scaled(&self, other: &Matrix<K>, s: K) -> Matrix<K> {
+        let mut result = self.clone();
+        result.add(&other.scale(s));
+        result
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{matrix::Matrix, vector::Vector};
-
     use super::*;
 
     #[test]
@@ -36,7 +88,7 @@ mod tests {
     fn lerp_works_with_vectors() {
         let v1 = Vector::from(&[2., 1.]);
         let v2 = Vector::from(&[4., 2.]);
-        assert_eq!(Vector::lerp(v1, v2, 0.3), Vector::from(&[2.6, 1.3]));
+        assert_eq!(Vector::<f32>::lerp(v1, v2, 0.3), Vector::from(&[2.6, 1.3]));
     }
 
     #[test]
@@ -45,8 +97,22 @@ mod tests {
         let m2 = Matrix::from(&[&[20., 10.], &[30., 40.]]);
 
         assert_eq!(
-            Matrix::lerp(m1, m2, 0.5),
+            Matrix::<f32>::lerp(m1, m2, 0.5),
             Matrix::from(&[&[11., 5.5], &[16.5, 22.]])
         );
     }
+
+    #[test]
+    fn lerp_works_with_f64_vectors_and_matrices() {
+        let v1: Vector<f64> = Vector::from(&[2., 1.]);
+        let v2 = Vector::from(&[4., 2.]);
+        assert_eq!(Vector::lerp(v1, v2, 0.25), Vector::from(&[2.5, 1.25]));
+        let m1: Matrix<f64> = Matrix::from(&[&[0., 1.]]);
+        let m2 = Matrix::from(&[&[4., -1.]]);
+        assert_eq!(
+            Interpolate::lerp(m1, m2, 0.75),
+            Matrix::from(&[&[3., -0.5]])
+        );
+        assert_eq!(f64::lerp(1., 3., 0.5), 2.);
+    }
 }
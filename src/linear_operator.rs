@@ -0,0 +1,382 @@
+use crate::{
+    matrix::Matrix,
+    numeric::Numeric,
+    sparse::{CscMatrix, CsrMatrix},
+    structured::DiagonalMatrix,
+    vector::Vector,
+};
+
+pub trait LinearOperator<K: Numeric> {
+    fn shape(&self) -> (usize, usize);
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K>;
+}
+
+// Operators that can also apply A^T. Kept separate so that an operator without a
+// transpose is rejected at compile time by code that needs one.
+pub trait TransposeOperator<K: Numeric>: LinearOperator<K> {
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K>;
+}
+
+fn check_len(expected: usize, actual: usize) {
+    if expected != actual {
+        panic!("Operator and vector dimensions don't match");
+    }
+}
+
+impl<K: Numeric, A: LinearOperator<K> + ?Sized> LinearOperator<K> for &A {
+    fn shape(&self) -> (usize, usize) {
+        (**self).shape()
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        (**self).apply(x)
+    }
+}
+
+impl<K: Numeric, A: TransposeOperator<K> + ?Sized> TransposeOperator<K> for &A {
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        (**self).apply_transpose(x)
+    }
+}
+
+impl<K: Numeric> LinearOperator<K> for Matrix<K> {
+    fn shape(&self) -> (usize, usize) {
+        let rows = self.values.len();
+        let cols = if rows > 0 { self.values[0].len() } else { 0 };
+        (rows, cols)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x.clone())
+    }
+}
+
+impl<K: Numeric> TransposeOperator<K> for Matrix<K> {
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        let (rows, cols) = self.shape();
+        check_len(rows, x.values.len());
+        let mut result = vec![K::zero(); cols];
+        for (row, &xi) in self.values.iter().zip(x.values.iter()) {
+            for (target, &a) in result.iter_mut().zip(row) {
+                *target += a * xi;
+            }
+        }
+        Vector { values: result }
+    }
+}
+
+impl<K: Numeric> LinearOperator<K> for CsrMatrix<K> {
+    fn shape(&self) -> (usize, usize) {
+        (self.rows(), self.cols())
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+}
+
+impl<K: Numeric> TransposeOperator<K> for CsrMatrix<K> {
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        check_len(self.rows(), x.values.len());
+        let mut result = vec![K::zero(); self.cols()];
+        for (i, &xi) in x.values.iter().enumerate() {
+            for (j, a) in self.row(i) {
+                result[j] += a * xi;
+            }
+        }
+        Vector { values: result }
+    }
+}
+
+impl<K: Numeric> LinearOperator<K> for CscMatrix<K> {
+    fn shape(&self) -> (usize, usize) {
+        (self.rows(), self.cols())
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+}
+
+impl<K: Numeric> TransposeOperator<K> for CscMatrix<K> {
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        check_len(self.rows(), x.values.len());
+        let result: Vec<K> = (0..self.cols())
+            .map(|j| {
+                self.col(j)
+                    .fold(K::zero(), |acc, (i, a)| acc + a * x.values[i])
+            })
+            .collect();
+        Vector { values: result }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IdentityOperator {
+    n: usize,
+}
+
+impl IdentityOperator {
+    pub fn new(n: usize) -> Self {
+        IdentityOperator { n }
+    }
+}
+
+impl<K: Numeric> LinearOperator<K> for IdentityOperator {
+    fn shape(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        check_len(self.n, x.values.len());
+        x.clone()
+    }
+}
+
+impl<K: Numeric> TransposeOperator<K> for IdentityOperator {
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        self.apply(x)
+    }
+}
+
+// Kept for code written against the operator module; the diagonal matrix type
+// already is a transposable operator.
+pub type DiagonalOperator<K> = DiagonalMatrix<K>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScaledOperator<K: Numeric, A> {
+    scale: K,
+    operator: A,
+}
+
+impl<K: Numeric, A: LinearOperator<K>> ScaledOperator<K, A> {
+    pub fn new(scale: K, operator: A) -> Self {
+        ScaledOperator { scale, operator }
+    }
+}
+
+impl<K: Numeric, A: LinearOperator<K>> LinearOperator<K> for ScaledOperator<K, A> {
+    fn shape(&self) -> (usize, usize) {
+        self.operator.shape()
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        let mut result = self.operator.apply(x);
+        result.scl(self.scale);
+        result
+    }
+}
+
+impl<K: Numeric, A: TransposeOperator<K>> TransposeOperator<K> for ScaledOperator<K, A> {
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        let mut result = self.operator.apply_transpose(x);
+        result.scl(self.scale);
+        result
+    }
+}
+
+// The product `outer * inner`: applying it runs `inner` first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComposedOperator<A, B> {
+    outer: A,
+    inner: B,
+}
+
+impl<A, B> ComposedOperator<A, B> {
+    pub fn new<K: Numeric>(outer: A, inner: B) -> Self
+    where
+        A: LinearOperator<K>,
+        B: LinearOperator<K>,
+    {
+        if outer.shape().1 != inner.shape().0 {
+            panic!("Operator dimensions don't match");
+        }
+        ComposedOperator { outer, inner }
+    }
+}
+
+impl<K: Numeric, A: LinearOperator<K>, B: LinearOperator<K>> LinearOperator<K>
+    for ComposedOperator<A, B>
+{
+    fn shape(&self) -> (usize, usize) {
+        (self.outer.shape().0, self.inner.shape().1)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.outer.apply(&self.inner.apply(x))
+    }
+}
+
+impl<K: Numeric, A: TransposeOperator<K>, B: TransposeOperator<K>> TransposeOperator<K>
+    for ComposedOperator<A, B>
+{
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        self.inner.apply_transpose(&self.outer.apply_transpose(x))
+    }
+}
+
+type VectorFn<'a, K> = Box<dyn Fn(&Vector<K>) -> Vector<K> + 'a>;
+
+// Wraps closures so matrix-free kernels (stencils and the like) can be handed to
+// anything that takes a `LinearOperator`.
+pub struct FnOperator<'a, K: Numeric> {
+    shape: (usize, usize),
+    apply: VectorFn<'a, K>,
+}
+
+impl<'a, K: Numeric> FnOperator<'a, K> {
+    pub fn new(rows: usize, cols: usize, apply: impl Fn(&Vector<K>) -> Vector<K> + 'a) -> Self {
+        FnOperator {
+            shape: (rows, cols),
+            apply: Box::new(apply),
+        }
+    }
+
+    pub fn with_transpose(
+        self,
+        apply_transpose: impl Fn(&Vector<K>) -> Vector<K> + 'a,
+    ) -> TransposableFnOperator<'a, K> {
+        TransposableFnOperator {
+            operator: self,
+            apply_transpose: Box::new(apply_transpose),
+        }
+    }
+}
+
+impl<K: Numeric> LinearOperator<K> for FnOperator<'_, K> {
+    fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        check_len(self.shape.1, x.values.len());
+        (self.apply)(x)
+    }
+}
+
+// An `FnOperator` that was also given a closure for A^T.
+pub struct TransposableFnOperator<'a, K: Numeric> {
+    operator: FnOperator<'a, K>,
+    apply_transpose: VectorFn<'a, K>,
+}
+
+impl<K: Numeric> LinearOperator<K> for TransposableFnOperator<'_, K> {
+    fn shape(&self) -> (usize, usize) {
+        self.operator.shape()
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.operator.apply(x)
+    }
+}
+
+impl<K: Numeric> TransposeOperator<K> for TransposableFnOperator<'_, K> {
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        check_len(self.operator.shape.0, x.values.len());
+        (self.apply_transpose)(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::iterative::{conjugate_gradient, SolverOptions};
+
+    #[test]
+    fn dense_and_sparse_operators_agree() {
+        let m = Matrix::from(&[&[1., 2., 0.], &[0., 3., 4.]]);
+        let x = Vector::from(&[1., -1., 2.]);
+        let csr = CsrMatrix::from_dense(&m);
+        let csc = CscMatrix::from_dense(&m);
+        assert_eq!(m.shape(), (2, 3));
+        assert_eq!(csr.shape(), (2, 3));
+        assert_eq!(m.apply(&x), Vector::from(&[-1., 5.]));
+        assert_eq!(csr.apply(&x), m.apply(&x));
+        assert_eq!(csc.apply(&x), m.apply(&x));
+    }
+
+    #[test]
+    fn apply_transpose_agrees_across_formats() {
+        let m = Matrix::from(&[&[1., 2., 0.], &[0., 3., 4.]]);
+        let y = Vector::from(&[2., -1.]);
+        let expected = Vector::from(&[2., 1., -4.]);
+        assert_eq!(m.apply_transpose(&y), expected);
+        assert_eq!(CsrMatrix::from_dense(&m).apply_transpose(&y), expected);
+        assert_eq!(CscMatrix::from_dense(&m).apply_transpose(&y), expected);
+    }
+
+    #[test]
+    fn identity_and_diagonal_operators_work() {
+        let x = Vector::from(&[1, 2, 3]);
+        assert_eq!(IdentityOperator::new(3).apply(&x), x);
+        let d = DiagonalMatrix::new(&[2, 0, -1]);
+        assert_eq!(d.apply(&x), Vector::from(&[2, 0, -3]));
+        assert_eq!(d.apply_transpose(&x), d.apply(&x));
+        assert_eq!(DiagonalOperator::new(&[2, 0, -1]), d);
+    }
+
+    #[test]
+    fn scaled_and_composed_operators_work() {
+        let a = Matrix::from(&[&[1, 2], &[3, 4]]);
+        let b = Matrix::from(&[&[0, 1], &[1, 0]]);
+        let x = Vector::from(&[1, 1]);
+
+        let scaled = ScaledOperator::new(2, &a);
+        assert_eq!(scaled.apply(&x), Vector::from(&[6, 14]));
+
+        let composed = ComposedOperator::new(&a, &b);
+        assert_eq!(composed.shape(), (2, 2));
+        assert_eq!(
+            composed.apply(&Vector::from(&[1, 0])),
+            Vector::from(&[2, 4])
+        );
+        assert_eq!(
+            composed.apply_transpose(&Vector::from(&[1, 0])),
+            Vector::from(&[2, 1])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn composing_mismatched_operators_panics() {
+        let a = Matrix::from(&[&[1, 2, 3]]);
+        ComposedOperator::new(&a, &a);
+    }
+
+    #[test]
+    fn closure_operator_drives_a_solver() {
+        // Matrix-free 1D Laplacian stencil [-1, 2, -1].
+        let n = 16;
+        let stencil = FnOperator::new(n, n, |x: &Vector<f64>| {
+            let v = &x.values;
+            Vector {
+                values: (0..v.len())
+                    .map(|i| {
+                        let left = if i > 0 { v[i - 1] } else { 0. };
+                        let right = if i + 1 < v.len() { v[i + 1] } else { 0. };
+                        2. * v[i] - left - right
+                    })
+                    .collect(),
+            }
+        });
+        let b = Vector::from(&[1.; 16]);
+
+        let result = conjugate_gradient(&stencil, &b, None, &SolverOptions::default());
+
+        assert!(result.converged());
+        let mut r = b.clone();
+        r.sub(&stencil.apply(&result.solution));
+        assert!(r.norm_2() < 1e-8);
+    }
+
+    #[test]
+    fn closure_operator_with_transpose_applies_it() {
+        let m = Matrix::from(&[&[1., 2., 0.], &[0., 3., 4.]]);
+        let op = FnOperator::new(2, 3, |x: &Vector<f64>| m.apply(x))
+            .with_transpose(|y: &Vector<f64>| m.apply_transpose(y));
+        let y = Vector::from(&[2., -1.]);
+        assert_eq!(op.shape(), (2, 3));
+        assert_eq!(op.apply_transpose(&y), m.apply_transpose(&y));
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{matrix::Matrix, numeric::Numeric, vector::Vector};
+
+// PA = LU with partial pivoting. L (unit lower, below the diagonal) and U share
+// one matrix; `permutation[i]` is the row of A that ended up in row i.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LuDecomposition<K: Numeric> {
+    pub(crate) lu: Matrix<K>,
+    pub(crate) permutation: Vec<usize>,
+    pub(crate) swaps: usize,
+}
+
+impl<K: Numeric> Matrix<K> {
+    pub fn lu(&self) -> Result<LuDecomposition<K>, String> {
+        if !self.is_square() {
+            return Err("Matrix must be square to have an LU decomposition".to_string());
+        }
+        let n = self.values.len();
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&a, &b| {
+                    lu.values[a][k]
+                        .magnitude()
+                        .total_cmp(&lu.values[b][k].magnitude())
+                })
+                .unwrap();
+            if lu.values[pivot][k] == K::zero() {
+                return Err("Matrix is singular".to_string());
+            }
+            if pivot != k {
+                lu.values.swap(pivot, k);
+                permutation.swap(pivot, k);
+                swaps += 1;
+            }
+            for i in k + 1..n {
+                let factor = lu.values[i][k] / lu.values[k][k];
+                lu.values[i][k] = factor;
+                for j in k + 1..n {
+                    let value = lu.values[k][j];
+                    lu.values[i][j] = lu.values[i][j] - factor * value;
+                }
+            }
+        }
+        Ok(LuDecomposition {
+            lu,
+            permutation,
+            swaps,
+        })
+    }
+}
+
+impl<K: Numeric> LuDecomposition<K> {
+    pub fn size(&self) -> usize {
+        self.permutation.len()
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn l(&self) -> Matrix<K> {
+        let n = self.size();
+        Matrix {
+            values: (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| match j.cmp(&i) {
+                            std::cmp::Ordering::Less => self.lu.values[i][j],
+                            std::cmp::Ordering::Equal => K::one(),
+                            std::cmp::Ordering::Greater => K::zero(),
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    pub fn u(&self) -> Matrix<K> {
+        let n = self.size();
+        Matrix {
+            values: (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| {
+                            if j >= i {
+                                self.lu.values[i][j]
+                            } else {
+                                K::zero()
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    pub fn determinant(&self) -> K {
+        let product = (0..self.size()).fold(K::one(), |acc, i| acc * self.lu.values[i][i]);
+        if self.swaps % 2 == 1 {
+            -product
+        } else {
+            product
+        }
+    }
+
+    pub fn solve(&self, b: &Vector<K>) -> Vector<K> {
+        let n = self.size();
+        if b.values.len() != n {
+            panic!("Matrix and vector dimensions don't match");
+        }
+        let mut x: Vec<K> = self.permutation.iter().map(|&p| b.values[p]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] = x[i] - self.lu.values[i][j] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] = x[i] - self.lu.values[i][j] * x[j];
+            }
+            x[i] = x[i] / self.lu.values[i][i];
+        }
+        Vector { values: x }
+    }
+
+    // Column j of A^-1 is the solution of A x = e_j.
+    pub fn inverse(&self) -> Matrix<K> {
+        let n = self.size();
+        let mut result = Matrix {
+            values: vec![vec![K::zero(); n]; n],
+        };
+        for j in 0..n {
+            let mut e = vec![K::zero(); n];
+            e[j] = K::one();
+            let column = self.solve(&Vector { values: e });
+            for (row, x) in result.values.iter_mut().zip(column.values) {
+                row[j] = x;
+            }
+        }
+        result
+    }
+
+    // Solves A^T x = b, i.e. U^T L^T P x = b.
+    pub fn solve_transpose(&self, b: &Vector<K>) -> Vector<K> {
+        let n = self.size();
+        if b.values.len() != n {
+            panic!("Matrix and vector dimensions don't match");
+        }
+        let mut y = b.values.clone();
+        for i in 0..n {
+            for j in 0..i {
+                y[i] = y[i] - self.lu.values[j][i] * y[j];
+            }
+            y[i] = y[i] / self.lu.values[i][i];
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                y[i] = y[i] - self.lu.values[j][i] * y[j];
+            }
+        }
+        let mut x = vec![K::zero(); n];
+        for (i, &p) in self.permutation.iter().enumerate() {
+            x[p] = y[i];
+        }
+        Vector { values: x }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Matrix<f64> {
+        Matrix::from(&[&[8., 5., -2.], &[4., 7., 20.], &[7., 6., 1.]])
+    }
+
+    #[test]
+    fn lu_reconstructs_permuted_matrix() {
+        let a = example();
+        let lu = a.lu().unwrap();
+        let product = lu.l().mul_mat(lu.u());
+        for (i, &p) in lu.permutation().iter().enumerate() {
+            for j in 0..3 {
+                assert!((product.values[i][j] - a.values[p][j]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn determinant_works() {
+        let lu = example().lu().unwrap();
+        assert!((lu.determinant() - -174.).abs() < 1e-12);
+        let swapped = Matrix::from(&[&[0., 1.], &[1., 0.]]).lu().unwrap();
+        assert_eq!(swapped.determinant(), -1.);
+    }
+
+    #[test]
+    fn nan_entries_propagate_instead_of_panicking() {
+        let a = Matrix::from(&[&[f64::NAN, 1.], &[1., 1.]]);
+        assert!(a.lu().is_ok());
+        assert!(a.determinant().is_nan());
+        let inverse = a.inverse().unwrap();
+        assert!(inverse.values.iter().flatten().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn inverse_solves_for_every_unit_vector() {
+        let a = example();
+        let product = a.mul_mat(a.lu().unwrap().inverse());
+        for (i, row) in product.values.iter().enumerate() {
+            for (j, &x) in row.iter().enumerate() {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((x - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn solve_and_solve_transpose_work() {
+        let a = example();
+        let lu = a.lu().unwrap();
+        let b = Vector::from(&[1., 2., 3.]);
+
+        let x = lu.solve(&b);
+        let mut r = a.mul_vec(x);
+        r.sub(&b);
+        assert!(r.norm_2() < 1e-12);
+
+        let mut transposed = a.clone();
+        let x = lu.solve_transpose(&b);
+        let mut r = transposed.transpose().mul_vec(x);
+        r.sub(&b);
+        assert!(r.norm_2() < 1e-12);
+    }
+
+    #[test]
+    fn singular_matrix_is_rejected() {
+        let m = Matrix::from(&[&[1., 2.], &[2., 4.]]);
+        assert_eq!(m.lu().unwrap_err(), "Matrix is singular".to_string());
+        assert!(Matrix::from(&[&[1., 2.]]).lu().is_err());
+    }
+}
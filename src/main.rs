@@ -1,28 +1,11 @@
-use vector::Vector;
-
-pub mod complex_number;
-pub mod cos;
-pub mod cross_product;
-pub mod determinant;
-pub mod inverse;
-pub mod linear_combinations;
-pub mod linear_interpolation;
-pub mod matrix;
-pub mod matrix_multiplication;
-pub mod norm;
-pub mod numeric;
-pub mod rank;
-pub mod row_echelon_form;
-pub mod trace;
-pub mod transpose;
-pub mod vector;
+use ft_matrix::{linear_interpolation::Interpolate, matrix, vector, vector::Vector};
 
 fn main() {
     let v = vector::Vector {
         values: vec![1, 2, 3],
     };
 
-    println!("Vector: {:?}", v);
+    println!("Vector:\n{}", v);
 
     let m = matrix::Matrix {
         values: vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
@@ -36,10 +19,10 @@ fn main() {
         values: vec![4, 5, 6],
     };
 
-    let sum = v1.sub(&v2);
+    v1.sub(&v2);
 
-    println!("Sub of vectors: {:?}", sum);
-    println!("Matrix: {:?}", m);
+    println!("Sub of vectors:\n{}", v1);
+    println!("Matrix:\n{}", m);
 
     let start = vector::Vector {
         values: vec![0., 0., 0.],
@@ -53,7 +36,7 @@ fn main() {
 
     let lerped = Vector::lerp(start, end, t);
 
-    println!("Lerped Vector: {:?}", lerped);
+    println!("Lerped Vector:\n{}", lerped);
 
 
     let v3 = vector::Vector {
@@ -62,5 +45,5 @@ fn main() {
 
     let cross_product = v1.cross_product(&v3);
 
-    println!("Cross product: {:?}", cross_product);
+    println!("Cross product:\n{}", cross_product);
 }
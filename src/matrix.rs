@@ -7,6 +7,9 @@ pub struct Matrix<K: Numeric> {
 
 impl<K: Numeric> Matrix<K> {
     pub fn from(values: &[&[K]]) -> Self {
+        if values.iter().any(|row| row.len() != values[0].len()) {
+            panic!("Matrix rows must all have the same length");
+        }
         let matrix_values: Vec<Vec<K>> = values.iter().map(|row| row.to_vec()).collect();
         Matrix {
             values: matrix_values,
@@ -22,7 +25,7 @@ impl<K> Matrix<K>
 where
     K: Numeric,
 {
-    fn add(&mut self, v: &Matrix<K>) {
+    pub fn add(&mut self, v: &Matrix<K>) {
         if self.values.len() != v.values.len() {
             panic!("Matrices must have the same dimensions to add them");
         }
@@ -30,11 +33,11 @@ where
             if x.len() != y.len() {
                 panic!("Matrices must have the same dimensions to add them");
             }
-            x.iter_mut().zip(y).for_each(|(a, b)| *a = *a + *b)
+            x.iter_mut().zip(y).for_each(|(a, b)| *a += *b)
         });
     }
 
-    fn sub(&mut self, v: &Matrix<K>) {
+    pub fn sub(&mut self, v: &Matrix<K>) {
         if self.values.len() != v.values.len() {
             panic!("Matrices must have the same dimensions to subtract them");
         }
@@ -46,28 +49,23 @@ where
         });
     }
 
-    fn scl(&mut self, a: K) {
+    pub fn scl(&mut self, a: K) {
         self.values
             .iter_mut()
             .for_each(|x| x.iter_mut().for_each(|y| *y = *y * a));
     }
 }
 
-impl Matrix<f32> {
-    pub fn lerp(u: Matrix<f32>, v: Matrix<f32>, t: f32) -> Matrix<f32> {
-        let mut result = u.clone();
-        result.scl(1.0 - t);
-        let mut v = v.clone();
-        v.scl(t);
-        result.add(&v);
-        result
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    #[should_panic]
+    fn from_panics_on_ragged_rows() {
+        Matrix::from(&[&[1, 2], &[3]]);
+    }
+
     #[test]
     fn scale_properly_scales_a_matrix() {
         let mut v = Matrix {
@@ -0,0 +1,332 @@
+use num::Float;
+
+use crate::{linear_interpolation::Interpolate, matrix::Matrix, numeric::Numeric};
+
+fn constant<K: Numeric + Float>(x: f64) -> K {
+    K::from(x).unwrap()
+}
+
+// Solves A X = B column by column through one LU factorization of A.
+fn solve<K: Numeric + Float>(a: &Matrix<K>, b: &Matrix<K>) -> Result<Matrix<K>, String> {
+    let lu = a.lu()?;
+    let n = b.values.len();
+    let cols = b.values.first().map_or(0, |row| row.len());
+    let mut x = vec![vec![K::zero(); cols]; n];
+    for j in 0..cols {
+        let column = crate::vector::Vector {
+            values: b.values.iter().map(|row| row[j]).collect(),
+        };
+        for (i, value) in lu.solve(&column).values.into_iter().enumerate() {
+            x[i][j] = value;
+        }
+    }
+    Ok(Matrix { values: x })
+}
+
+fn difference_norm<K: Numeric + Float>(a: &Matrix<K>, b: &Matrix<K>) -> K {
+    a.add_scaled(b, -K::one()).norm_1()
+}
+
+// Coefficients of the degree 13 Pade approximant to exp and the largest 1-norm
+// it is accurate to double precision for (Higham, 2005).
+const PADE_13: [f64; 14] = [
+    64764752532480000.,
+    32382376266240000.,
+    7771770303897600.,
+    1187353796428800.,
+    129060195264000.,
+    10559470521600.,
+    670442572800.,
+    33522128640.,
+    1323241920.,
+    40840800.,
+    960960.,
+    16380.,
+    182.,
+    1.,
+];
+const THETA_13: f64 = 5.371920351148152;
+
+// Gauss-Legendre nodes and weights on [0, 1]; the quadrature of
+// log(I + X) = integral of X (I + tX)^-1 dt over [0, 1] is its [7/7] Pade approximant.
+const LOG_NODES: [(f64, f64); 7] = [
+    (0.025446043828620757, 0.06474248308443485),
+    (0.12923440720030277, 0.1398526957446383),
+    (0.2970774243113014, 0.19091502525255946),
+    (0.5, 0.2089795918367347),
+    (0.7029225756886986, 0.19091502525255946),
+    (0.8707655927996972, 0.1398526957446383),
+    (0.9745539561713792, 0.06474248308443485),
+];
+
+impl<K: Numeric + Float> Matrix<K> {
+    // Scaling and squaring: exp(A) = exp(A / 2^s)^(2^s), with exp(A / 2^s)
+    // from the [13/13] Pade approximant.
+    pub fn exp(&self) -> Result<Matrix<K>, String> {
+        if !self.is_square() {
+            return Err("Matrix must be square to calculate its exponential".to_string());
+        }
+        let n = self.values.len();
+        if self.values.iter().flatten().any(|x| !x.is_finite()) {
+            return Err("Matrix must be finite to calculate its exponential".to_string());
+        }
+        let norm = self.norm_1().to_f64().unwrap();
+        let s = if norm > THETA_13 {
+            (norm / THETA_13).log2().ceil() as i32
+        } else {
+            0
+        };
+        let a = self.scale(constant::<K>(0.5f64.powi(s)));
+        let b = |i: usize| constant::<K>(PADE_13[i]);
+        let ident = Matrix::<K>::identity(n);
+        let a2 = a.mul_mat(a.clone());
+        let a4 = a2.mul_mat(a2.clone());
+        let a6 = a4.mul_mat(a2.clone());
+        let u_inner = a6.scale(b(13)).add_scaled(&a4, b(11)).add_scaled(&a2, b(9));
+        let u = a.mul_mat(
+            a6.mul_mat(u_inner)
+                .add_scaled(&a6, b(7))
+                .add_scaled(&a4, b(5))
+                .add_scaled(&a2, b(3))
+                .add_scaled(&ident, b(1)),
+        );
+        let v_inner = a6.scale(b(12)).add_scaled(&a4, b(10)).add_scaled(&a2, b(8));
+        let v = a6
+            .mul_mat(v_inner)
+            .add_scaled(&a6, b(6))
+            .add_scaled(&a4, b(4))
+            .add_scaled(&a2, b(2))
+            .add_scaled(&ident, b(0));
+        let numerator = v.add_scaled(&u, K::one());
+        let denominator = v.add_scaled(&u, -K::one());
+        let mut result = solve(&denominator, &numerator)
+            .map_err(|_| "Pade denominator is singular".to_string())?;
+        for _ in 0..s {
+            result = result.mul_mat(result.clone());
+        }
+        Ok(result)
+    }
+
+    // Principal square root by the scaled Denman-Beavers iteration. Fails for
+    // singular matrices and ones with eigenvalues on the negative real axis.
+    pub fn sqrt(&self) -> Result<Matrix<K>, String> {
+        if !self.is_square() {
+            return Err("Matrix must be square to have a square root".to_string());
+        }
+        let n = self.values.len();
+        let half = constant::<K>(0.5);
+        // Convergence is quadratic, so one more step after the update drops below
+        // sqrt(eps) brings it to working precision.
+        let tolerance = K::epsilon().sqrt();
+        let mut finishing = false;
+        let mut y = self.clone();
+        let mut z = Matrix::<K>::identity(n);
+        for _ in 0..100 {
+            let y_inverse = solve(&y, &Matrix::identity(n))
+                .map_err(|_| "Matrix has no principal square root".to_string())?;
+            let z_inverse = solve(&z, &Matrix::identity(n))
+                .map_err(|_| "Matrix has no principal square root".to_string())?;
+            // Determinant scaling speeds up the early iterations.
+            let det = (y.lu()?.determinant() * z.lu()?.determinant()).abs();
+            let mu = det.powf(-K::one() / constant::<K>(2. * n as f64));
+            let mu = if mu.is_finite() && mu > K::zero() {
+                mu
+            } else {
+                K::one()
+            };
+            let next_y = y.scale(half * mu).add_scaled(&z_inverse, half / mu);
+            let next_z = z.scale(half * mu).add_scaled(&y_inverse, half / mu);
+            let change = difference_norm(&next_y, &y);
+            y = next_y;
+            z = next_z;
+            if finishing {
+                return if y.values.iter().flatten().all(|x| x.is_finite()) {
+                    Ok(y)
+                } else {
+                    Err("Matrix has no principal square root".to_string())
+                };
+            }
+            finishing = change <= tolerance * y.norm_1();
+        }
+        Err("Matrix square root did not converge".to_string())
+    }
+
+    // Inverse scaling and squaring: take square roots until A is close to I,
+    // then log(A) = 2^k log(I + X) with a Pade approximant for log(I + X).
+    pub fn log(&self) -> Result<Matrix<K>, String> {
+        if !self.is_square() {
+            return Err("Matrix must be square to have a logarithm".to_string());
+        }
+        let n = self.values.len();
+        let ident = Matrix::<K>::identity(n);
+        let mut a = self.clone();
+        let mut k = 0;
+        while difference_norm(&a, &ident) > constant::<K>(0.25) {
+            if k == 64 {
+                return Err("Matrix logarithm did not converge".to_string());
+            }
+            a = a
+                .sqrt()
+                .map_err(|_| "Matrix has no principal logarithm".to_string())?;
+            k += 1;
+        }
+        let x = a.add_scaled(&ident, -K::one());
+        let mut result = Matrix::zeros(n, n);
+        for &(node, weight) in LOG_NODES.iter() {
+            let denominator = ident.add_scaled(&x, constant::<K>(node));
+            result = result.add_scaled(&solve(&denominator, &x)?, constant::<K>(weight));
+        }
+        Ok(result.scale(constant::<K>(2f64.powi(k))))
+    }
+
+    // Repeated squaring; negative powers go through the inverse.
+    pub fn powi(&self, exponent: i32) -> Result<Matrix<K>, String> {
+        if !self.is_square() {
+            return Err("Matrix must be square to raise it to a power".to_string());
+        }
+        let mut base = if exponent < 0 {
+            solve(self, &Matrix::identity(self.values.len()))
+                .map_err(|_| "Matrix is not invertible".to_string())?
+        } else {
+            self.clone()
+        };
+        let mut exponent = exponent.unsigned_abs();
+        let mut result = Matrix::<K>::identity(self.values.len());
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul_mat(base.clone());
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul_mat(base.clone());
+            }
+        }
+        Ok(result)
+    }
+
+    // A^p = exp(p log A) for non-integer p; integer p uses powi.
+    pub fn powf(&self, exponent: K) -> Result<Matrix<K>, String> {
+        if exponent.fract() == K::zero() && exponent.abs() <= constant::<K>(i32::MAX as f64) {
+            return self.powi(exponent.to_i32().unwrap());
+        }
+        self.log()?.scale(exponent).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A = P D P^-1 with P = [[1, 1], [1, 2]], P^-1 = [[2, -1], [-1, 1]].
+    fn diagonalised(d: [f64; 2]) -> Matrix<f64> {
+        Matrix {
+            values: vec![
+                vec![2. * d[0] - d[1], -d[0] + d[1]],
+                vec![2. * d[0] - 2. * d[1], -d[0] + 2. * d[1]],
+            ],
+        }
+    }
+
+    fn assert_relative_close(actual: &Matrix<f64>, expected: &Matrix<f64>, tolerance: f64) {
+        let error = difference_norm(actual, expected) / expected.norm_1();
+        assert!(
+            error < tolerance,
+            "{:?} != {:?} ({})",
+            actual,
+            expected,
+            error
+        );
+    }
+
+    #[test]
+    fn exp_works() {
+        assert_eq!(
+            Matrix::from(&[&[0., 0.], &[0., 0.]]).exp().unwrap(),
+            Matrix::identity(2)
+        );
+        let nilpotent = Matrix::from(&[&[0., 1.], &[0., 0.]]);
+        assert_relative_close(
+            &nilpotent.exp().unwrap(),
+            &Matrix::from(&[&[1., 1.], &[0., 1.]]),
+            1e-15,
+        );
+        for d in [[1., -2.], [0.1, 0.3], [10., -5.], [-30., 2.5]] {
+            let expected = diagonalised([d[0].exp(), d[1].exp()]);
+            assert_relative_close(&diagonalised(d).exp().unwrap(), &expected, 1e-13);
+        }
+        let rotation = Matrix::from(&[&[0., -std::f64::consts::PI], &[std::f64::consts::PI, 0.]]);
+        assert_relative_close(
+            &rotation.exp().unwrap(),
+            &Matrix::from(&[&[-1., 0.], &[0., -1.]]),
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn exp_rejects_non_square_and_non_finite_matrices() {
+        assert!(Matrix::from(&[&[1., 2.]]).exp().is_err());
+        assert!(Matrix::from(&[&[f64::NAN, 0.], &[0., 1.]]).exp().is_err());
+        assert!(Matrix::from(&[&[f64::INFINITY]]).exp().is_err());
+    }
+
+    #[test]
+    fn sqrt_works() {
+        let a = Matrix::from(&[&[4., 0.], &[0., 9.]]);
+        assert_relative_close(
+            &a.sqrt().unwrap(),
+            &Matrix::from(&[&[2., 0.], &[0., 3.]]),
+            1e-15,
+        );
+        for d in [[1., 4.], [0.01, 100.], [2., 3.]] {
+            let expected = diagonalised([d[0].sqrt(), d[1].sqrt()]);
+            assert_relative_close(&diagonalised(d).sqrt().unwrap(), &expected, 1e-13);
+        }
+        let root = diagonalised([2., 5.]).sqrt().unwrap();
+        assert_relative_close(&root.mul_mat(root.clone()), &diagonalised([2., 5.]), 1e-14);
+        assert!(Matrix::from(&[&[1., 0.], &[0., 0.]]).sqrt().is_err());
+        assert!(Matrix::from(&[&[-1., 0.], &[0., 1.]]).sqrt().is_err());
+    }
+
+    #[test]
+    fn log_works() {
+        assert_relative_close(
+            &Matrix::<f64>::identity(3)
+                .log()
+                .unwrap()
+                .add_scaled(&Matrix::identity(3), 1.),
+            &Matrix::identity(3),
+            1e-15,
+        );
+        for d in [[1.5, 0.5], [100., 0.01], [std::f64::consts::E, 3.]] {
+            let expected = diagonalised([d[0].ln(), d[1].ln()]);
+            assert_relative_close(&diagonalised(d).log().unwrap(), &expected, 1e-12);
+        }
+        let a = Matrix::from(&[&[0.5, 1.], &[-0.3, 0.2]]);
+        assert_relative_close(&a.exp().unwrap().log().unwrap(), &a, 1e-13);
+        assert!(Matrix::from(&[&[-1., 0.], &[0., 2.]]).log().is_err());
+    }
+
+    #[test]
+    fn powi_works() {
+        let a = Matrix::from(&[&[1., 1.], &[1., 0.]]);
+        assert_eq!(
+            a.powi(10).unwrap(),
+            Matrix::from(&[&[89., 55.], &[55., 34.]])
+        );
+        assert_eq!(a.powi(0).unwrap(), Matrix::identity(2));
+        assert_relative_close(
+            &a.powi(-3).unwrap().mul_mat(a.powi(3).unwrap()),
+            &Matrix::identity(2),
+            1e-15,
+        );
+        assert!(Matrix::from(&[&[1., 2.], &[2., 4.]]).powi(-1).is_err());
+    }
+
+    #[test]
+    fn powf_works() {
+        let a = diagonalised([4., 9.]);
+        assert_relative_close(&a.powf(0.5).unwrap(), &a.sqrt().unwrap(), 1e-13);
+        assert_relative_close(&a.powf(1.5).unwrap(), &diagonalised([8., 27.]), 1e-13);
+        assert_eq!(a.powf(2.).unwrap(), a.powi(2).unwrap());
+    }
+}
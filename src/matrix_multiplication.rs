@@ -1,10 +1,24 @@
-use crate::{matrix::Matrix, numeric::Numeric, vector::Vector};
+use crate::{
+    matrix::Matrix,
+    numeric::Numeric,
+    summation::{self, summation_policy, Summation},
+    vector::Vector,
+};
 
 impl<K: Numeric> Matrix<K> {
-    fn mul_vec(&self, vec: Vector<K>) -> Vector<K> {
+    pub fn mul_vec(&self, vec: Vector<K>) -> Vector<K> {
         if self.values[0].len() != vec.values.len() {
             panic!("Matrix and vector dimensions don't match");
         }
+        let method = summation_policy();
+        if method != Summation::Naive {
+            let result: Vec<K> = self
+                .values
+                .iter()
+                .map(|row| summation::dot(row, &vec.values, method))
+                .collect();
+            return Vector::<K>::from(&result);
+        }
         let result: Vec<K> = self
             .values
             .iter()
@@ -17,7 +31,32 @@ impl<K: Numeric> Matrix<K> {
         Vector::<K>::from(&result)
     }
 
-    fn mul_mat(&self, mat: Matrix<K>) -> Matrix<K> {
+    pub fn mul_mat(&self, mat: Matrix<K>) -> Matrix<K> {
+        self.mul_mat_with(&mat, summation_policy())
+    }
+
+    pub fn mul_mat_with(&self, mat: &Matrix<K>, method: Summation) -> Matrix<K> {
+        if method != Summation::Naive {
+            let packed = PackedRhs::new(self, mat);
+            return Matrix {
+                values: self
+                    .values
+                    .iter()
+                    .map(|row| {
+                        (0..packed.cols)
+                            .map(|j| summation::dot(row, packed.column(j), method))
+                            .collect()
+                    })
+                    .collect(),
+            };
+        }
+        #[cfg(feature = "rayon")]
+        return self.mul_mat_parallel(mat);
+        #[cfg(not(feature = "rayon"))]
+        return self.mul_mat_blocked(mat);
+    }
+
+    pub fn mul_mat_naive(&self, mat: &Matrix<K>) -> Matrix<K> {
         if self.values[0].len() != mat.values.len() {
             panic!("Matrix dimensions don't match");
         }
@@ -33,6 +72,95 @@ impl<K: Numeric> Matrix<K> {
         }
         result
     }
+
+    pub fn mul_mat_blocked(&self, mat: &Matrix<K>) -> Matrix<K> {
+        let packed = PackedRhs::new(self, mat);
+        let mut result = packed.empty_result(self.values.len());
+        for (block, rows) in result.values.chunks_mut(BLOCK).enumerate() {
+            packed.multiply_rows(&self.values[block * BLOCK..], rows);
+        }
+        result
+    }
+
+    #[cfg(feature = "rayon")]
+    pub fn mul_mat_parallel(&self, mat: &Matrix<K>) -> Matrix<K> {
+        use rayon::prelude::*;
+
+        let packed = PackedRhs::new(self, mat);
+        let mut result = packed.empty_result(self.values.len());
+        result
+            .values
+            .par_chunks_mut(BLOCK)
+            .enumerate()
+            .for_each(|(block, rows)| packed.multiply_rows(&self.values[block * BLOCK..], rows));
+        result
+    }
+}
+
+const BLOCK: usize = 64;
+
+// The right-hand operand stored transposed in one contiguous buffer, so the
+// inner loop walks both operands sequentially. Every entry is still summed in
+// ascending k order starting from zero, exactly like the naive kernel, which
+// keeps the blocked results bit-identical to it (for floats as well).
+struct PackedRhs<K: Numeric> {
+    inner: usize,
+    cols: usize,
+    transposed: Vec<K>,
+}
+
+impl<K: Numeric> PackedRhs<K> {
+    fn new(lhs: &Matrix<K>, rhs: &Matrix<K>) -> Self {
+        if lhs.values[0].len() != rhs.values.len() {
+            panic!("Matrix dimensions don't match");
+        }
+        let inner = rhs.values.len();
+        let cols = rhs.values[0].len();
+        let mut transposed = vec![K::zero(); inner * cols];
+        for (k, row) in rhs.values.iter().enumerate() {
+            if row.len() != cols {
+                panic!("Matrix rows must all have the same length");
+            }
+            for (j, &value) in row.iter().enumerate() {
+                transposed[j * inner + k] = value;
+            }
+        }
+        PackedRhs {
+            inner,
+            cols,
+            transposed,
+        }
+    }
+
+    fn column(&self, j: usize) -> &[K] {
+        &self.transposed[j * self.inner..(j + 1) * self.inner]
+    }
+
+    fn empty_result(&self, rows: usize) -> Matrix<K> {
+        Matrix {
+            values: vec![vec![K::zero(); self.cols]; rows],
+        }
+    }
+
+    fn multiply_rows(&self, lhs: &[Vec<K>], out: &mut [Vec<K>]) {
+        for kk in (0..self.inner).step_by(BLOCK) {
+            let k_end = (kk + BLOCK).min(self.inner);
+            for jj in (0..self.cols).step_by(BLOCK) {
+                let j_end = (jj + BLOCK).min(self.cols);
+                for (a, c) in lhs.iter().zip(out.iter_mut()) {
+                    let a = &a[kk..k_end];
+                    for (j, target) in c.iter_mut().enumerate().take(j_end).skip(jj) {
+                        let b = &self.transposed[j * self.inner + kk..j * self.inner + k_end];
+                        let mut acc = *target;
+                        for (&x, &y) in a.iter().zip(b) {
+                            acc += x * y;
+                        }
+                        *target = acc;
+                    }
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -133,4 +261,86 @@ mod tests {
 
         assert_eq!(result, Matrix::from(&[&[-14., -7.], &[44., 22.],]));
     }
+
+    fn pseudo_random(rows: usize, cols: usize, seed: i64) -> Matrix<i32> {
+        Matrix {
+            values: (0..rows)
+                .map(|i| {
+                    (0..cols)
+                        .map(|j| (((i as i64 * 31 + j as i64 * 17 + seed) % 19) - 9) as i32)
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn blocked_multiplication_matches_naive_for_integers() {
+        let a = pseudo_random(70, 130, 3);
+        let b = pseudo_random(130, 67, 5);
+
+        assert_eq!(a.mul_mat_blocked(&b), a.mul_mat_naive(&b));
+        assert_eq!(a.mul_mat(b.clone()), a.mul_mat_naive(&b));
+    }
+
+    #[test]
+    fn blocked_multiplication_is_bit_identical_for_floats() {
+        let a = Matrix {
+            values: (0..65)
+                .map(|i| (0..129).map(|j| ((i * j) as f64).sin()).collect())
+                .collect(),
+        };
+        let b = Matrix {
+            values: (0..129)
+                .map(|i| (0..66).map(|j| ((i + 2 * j) as f64).cos() / 3.).collect())
+                .collect(),
+        };
+
+        assert_eq!(a.mul_mat_blocked(&b), a.mul_mat_naive(&b));
+    }
+
+    #[test]
+    fn compensated_multiplication_keeps_small_contributions() {
+        let m1 = Matrix::from(&[&[1e16, 1., -1e16]]);
+        let m2 = Matrix::from(&[&[1.], &[1.], &[1.]]);
+
+        assert_eq!(
+            m1.mul_mat_with(&m2, Summation::Naive).values,
+            vec![vec![0.]]
+        );
+        assert_eq!(
+            m1.mul_mat_with(&m2, Summation::KahanBabuska).values,
+            vec![vec![1.]]
+        );
+    }
+
+    #[test]
+    fn summation_policy_applies_to_mul_mat_and_mul_vec() {
+        let m = Matrix::from(&[&[1e16, 1., -1e16]]);
+        summation::set_summation_policy(Summation::Exact);
+        let product = m.mul_mat(Matrix::from(&[&[1.], &[1.], &[1.]]));
+        let applied = m.mul_vec(Vector::from(&[1., 1., 1.]));
+        summation::set_summation_policy(Summation::Naive);
+
+        assert_eq!(product.values, vec![vec![1.]]);
+        assert_eq!(applied, Vector::from(&[1.]));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_multiplication_matches_naive() {
+        let a = pseudo_random(150, 90, 1);
+        let b = pseudo_random(90, 140, 2);
+
+        assert_eq!(a.mul_mat_parallel(&b), a.mul_mat_naive(&b));
+    }
+
+    #[test]
+    #[should_panic]
+    fn blocked_multiplication_panics_if_dimensions_dont_match() {
+        let m1 = Matrix::from(&[&[1., 2., 3.]]);
+        let m2 = Matrix::from(&[&[1., 2.], &[3., 4.]]);
+
+        m1.mul_mat_blocked(&m2);
+    }
 }
@@ -1,13 +1,34 @@
 use num::Float;
 
-use crate::{numeric::Numeric, vector::Vector};
+use crate::{
+    linear_operator::{LinearOperator, TransposeOperator},
+    matrix::Matrix,
+    numeric::Numeric,
+    simd,
+    summation::{self, summation_policy, Summation},
+    vector::Vector,
+};
 
 impl<V: Numeric> Vector<V> {
     pub fn norm_1(&self) -> f64 {
+        self.norm_1_with(summation_policy())
+    }
+
+    pub fn norm_1_with(&self, method: Summation) -> f64 {
+        if method != Summation::Naive {
+            let magnitudes: Vec<f64> = self.values.iter().map(|x| x.magnitude()).collect();
+            return summation::sum(&magnitudes, method);
+        }
+        if let Some(result) = simd::norm_1(&self.values) {
+            return result;
+        }
         self.values.iter().fold(0., |acc, x| acc + x.magnitude())
     }
 
     pub fn norm_inf(&self) -> f64 {
+        if let Some(result) = simd::norm_inf(&self.values) {
+            return result;
+        }
         self.values.iter().fold(0., |acc, x| {
             if x.magnitude() > acc {
                 x.magnitude()
@@ -20,10 +41,252 @@ impl<V: Numeric> Vector<V> {
 
 impl<V: Numeric + Float> Vector<V> {
     pub fn norm_2(&self) -> V {
+        self.norm_2_with(summation_policy())
+    }
+
+    pub fn norm_2_with(&self, method: Summation) -> V {
+        match self.values[..] {
+            [x, y] => return x.hypot(y),
+            [x, y, z] => return x.hypot(y).hypot(z),
+            _ => {}
+        }
+        let unscaled = if method != Summation::Naive {
+            summation::dot(&self.values, &self.values, method).sqrt()
+        } else if let Some(result) = simd::norm_2(&self.values) {
+            result
+        } else {
+            self.values
+                .iter()
+                .fold(V::zero(), |acc, &x| acc + x * x)
+                .sqrt()
+        };
+        if unscaled.is_finite() && unscaled >= safe_minimum() {
+            return unscaled;
+        }
+        scaled_norm_2(&self.values, method)
+    }
+}
+
+// Below this the sum of squares may have lost entries to underflow, and the
+// slower scaled algorithm takes over (as it does on overflow).
+fn safe_minimum<V: Float>() -> V {
+    (V::min_positive_value() / V::epsilon()).sqrt()
+}
+
+// Divides every entry by the largest magnitude before squaring, like LAPACK's
+// nrm2, so the squares can neither overflow nor underflow.
+fn scaled_norm_2<V: Numeric + Float>(values: &[V], method: Summation) -> V {
+    let mut max = V::zero();
+    for &x in values {
+        if x.is_nan() {
+            return x;
+        }
+        max = max.max(x.abs());
+    }
+    if max == V::zero() || max.is_infinite() {
+        return max;
+    }
+    let scaled: Vec<V> = values.iter().map(|&x| x / max).collect();
+    summation::dot(&scaled, &scaled, method).sqrt() * max
+}
+
+impl<K: Numeric + Float> Matrix<K> {
+    pub fn norm_frobenius(&self) -> K {
+        let values: Vec<K> = self.values.iter().flatten().copied().collect();
+        Vector { values }.norm_2()
+    }
+
+    pub fn norm_1(&self) -> K {
+        let cols = if self.values.is_empty() {
+            0
+        } else {
+            self.values[0].len()
+        };
+        (0..cols)
+            .map(|j| {
+                let column: Vec<K> = self.values.iter().map(|row| row[j].abs()).collect();
+                summation::sum(&column, summation_policy())
+            })
+            .fold(K::zero(), K::max)
+    }
+
+    pub fn norm_inf(&self) -> K {
         self.values
             .iter()
-            .fold(V::zero(), |acc, &x| acc + x * x)
-            .sqrt()
+            .map(|row| {
+                let row: Vec<K> = row.iter().map(|x| x.abs()).collect();
+                summation::sum(&row, summation_policy())
+            })
+            .fold(K::zero(), K::max)
+    }
+
+    pub fn norm_max(&self) -> K {
+        self.values
+            .iter()
+            .flatten()
+            .fold(K::zero(), |acc, x| acc.max(x.abs()))
+    }
+
+    // Power iteration on A^T A. The matrix is divided by its largest entry
+    // first so the products stay in range, and the result is scaled back.
+    pub fn norm_spectral_estimate(&self, max_iterations: usize) -> K {
+        let max = self.norm_max();
+        if max == K::zero() || !max.is_finite() {
+            return max;
+        }
+        let mut scaled = self.clone();
+        scaled.scl(K::one() / max);
+
+        let (_, cols) = scaled.shape();
+        let n = K::from(cols).unwrap();
+        let mut x = Vector {
+            values: (0..cols)
+                .map(|i| K::one() + K::from(i).unwrap() / n)
+                .collect(),
+        };
+        x.scl(K::one() / x.norm_2());
+        let tolerance = K::epsilon().sqrt();
+        let mut estimate = K::zero();
+        for _ in 0..max_iterations {
+            let y = scaled.apply(&x);
+            let next = y.norm_2();
+            let mut z = scaled.apply_transpose(&y);
+            let z_norm = z.norm_2();
+            if z_norm == K::zero() {
+                return next * max;
+            }
+            z.scl(K::one() / z_norm);
+            x = z;
+            let converged = (next - estimate).abs() <= tolerance * next;
+            estimate = next;
+            if converged {
+                break;
+            }
+        }
+        estimate * max
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NormKind<'a> {
+    One,
+    Two,
+    Inf,
+    P(f64),
+    WeightedL2(&'a Vector<f64>),
+    Mahalanobis(&'a Matrix<f64>),
+}
+
+impl<V: Numeric + Into<f64>> Vector<V> {
+    pub fn norm(&self, kind: NormKind) -> Result<f64, String> {
+        let magnitudes = || Vector {
+            values: self.values.iter().map(|x| x.magnitude()).collect(),
+        };
+        match kind {
+            NormKind::One => Ok(self.norm_1()),
+            NormKind::Two => Ok(magnitudes().norm_2()),
+            NormKind::Inf => Ok(self.norm_inf()),
+            NormKind::P(p) if p.is_nan() || p < 1. => {
+                Err(format!("p-norms need p >= 1, got {}", p))
+            }
+            NormKind::P(p) => Ok(if p == 1. {
+                self.norm_1()
+            } else if p == 2. {
+                magnitudes().norm_2()
+            } else if p == f64::INFINITY {
+                self.norm_inf()
+            } else {
+                p_norm(&magnitudes().values, p)
+            }),
+            NormKind::WeightedL2(weights) => {
+                if weights.values.len() != self.values.len() {
+                    return Err("Weights and vector must be the same length".to_string());
+                }
+                if weights.values.iter().any(|&w| w.is_nan() || w < 0.) {
+                    return Err("Weights must be non-negative".to_string());
+                }
+                Ok(Vector {
+                    values: self
+                        .values
+                        .iter()
+                        .zip(weights.values.iter())
+                        .map(|(x, w)| x.magnitude() * w.sqrt())
+                        .collect(),
+                }
+                .norm_2())
+            }
+            NormKind::Mahalanobis(m) => {
+                let values: Vec<f64> = self.values.iter().map(|&x| x.into()).collect();
+                mahalanobis(&values, m)
+            }
+        }
+    }
+}
+
+// (sum |x|^p)^(1/p), computed relative to the largest entry to stay in range.
+fn p_norm(magnitudes: &[f64], p: f64) -> f64 {
+    if magnitudes.iter().any(|x| x.is_nan()) {
+        return f64::NAN;
+    }
+    let max = magnitudes.iter().fold(0., |acc: f64, &x| acc.max(x));
+    if max == 0. || max.is_infinite() {
+        return max;
+    }
+    let terms: Vec<f64> = magnitudes.iter().map(|&x| (x / max).powf(p)).collect();
+    max * summation::sum(&terms, summation_policy()).powf(1. / p)
+}
+
+// sqrt(x^T M x) computed as ||L^T x|| from the Cholesky factor M = L L^T, which
+// also checks that M is symmetric positive definite.
+fn mahalanobis(x: &[f64], m: &Matrix<f64>) -> Result<f64, String> {
+    let n = x.len();
+    if m.values.len() != n || m.values.iter().any(|row| row.len() != n) {
+        return Err("Mahalanobis matrix must be square and match the vector".to_string());
+    }
+    let mut l = vec![vec![0.; n]; n];
+    for i in 0..n {
+        for j in 0..=i {
+            let scale = m.values[i][j].abs().max(m.values[j][i].abs());
+            if (m.values[i][j] - m.values[j][i]).abs() > 1e-12 * scale {
+                return Err("Mahalanobis matrix must be symmetric".to_string());
+            }
+            let sum = (0..j).fold(m.values[i][j], |acc, k| acc - l[i][k] * l[j][k]);
+            if i == j {
+                if sum.is_nan() || sum <= 0. {
+                    return Err("Mahalanobis matrix must be positive definite".to_string());
+                }
+                l[i][i] = sum.sqrt();
+            } else {
+                l[i][j] = sum / l[j][j];
+            }
+        }
+    }
+    let transformed: Vec<f64> = (0..n)
+        .map(|j| (j..n).fold(0., |acc, i| acc + l[i][j] * x[i]))
+        .collect();
+    Ok(Vector {
+        values: transformed,
+    }
+    .norm_2())
+}
+
+impl<V: Numeric + Float> Vector<V> {
+    pub fn normalize(&mut self) -> Result<(), String> {
+        let norm = self.norm_2();
+        if norm == V::zero() {
+            return Err("Cannot normalize a zero vector".to_string());
+        }
+        if !norm.is_finite() {
+            return Err("Cannot normalize a vector with a non-finite norm".to_string());
+        }
+        self.values.iter_mut().for_each(|x| *x = *x / norm);
+        Ok(())
+    }
+
+    pub fn normalized(&self) -> Result<Vector<V>, String> {
+        let mut result = self.clone();
+        result.normalize()?;
+        Ok(result)
     }
 }
 
@@ -47,6 +310,18 @@ mod tests {
         assert_eq!(v.norm_inf(), 3.0);
     }
 
+    #[test]
+    fn compensated_norms_work() {
+        let v = Vector::from(&[1e16, 1., -1., 1., -1.]);
+        assert_eq!(v.norm_1_with(Summation::Naive), 1e16);
+        assert_eq!(v.norm_1_with(Summation::KahanBabuska), 1e16 + 4.);
+        assert_eq!(v.norm_1_with(Summation::Exact), 1e16 + 4.);
+        assert_eq!(Vector::from(&[3., 4.]).norm_2_with(Summation::Exact), 5.);
+        let infinite = Vector::from(&[1., f64::INFINITY, 2., 3.]);
+        assert_eq!(infinite.norm_1_with(Summation::KahanBabuska), f64::INFINITY);
+        assert_eq!(infinite.norm_1_with(Summation::Exact), f64::INFINITY);
+    }
+
     #[test]
     fn norm_3_works() {
         let v = Vector::from(&[-1., -2.]);
@@ -54,4 +329,116 @@ mod tests {
         assert_eq!(v.norm_1(), 3.0);
         assert_eq!(v.norm_inf(), 2.0);
     }
+
+    #[test]
+    fn norm_2_does_not_overflow_or_underflow() {
+        let big = Vector::from(&[1e200, 1e200, 1e200, 1e200]);
+        assert_eq!(big.norm_2(), 2e200);
+        let small = Vector::from(&[1e-200, 1e-200, 1e-200, 1e-200]);
+        assert_eq!(small.norm_2(), 2e-200);
+        let big_f32 = Vector::from(&[1e30f32, 1e30, 1e30, 1e30]);
+        assert_eq!(big_f32.norm_2(), 2e30);
+        assert_eq!(big.norm_2_with(Summation::KahanBabuska), 2e200);
+    }
+
+    #[test]
+    fn norm_2_uses_hypot_for_two_and_three_entries() {
+        assert_eq!(Vector::from(&[3e300, 4e300]).norm_2(), 5e300);
+        assert_eq!(Vector::from(&[2e-300, 3e-300, 6e-300]).norm_2(), 7e-300);
+    }
+
+    #[test]
+    fn norm_2_propagates_nan_and_infinity() {
+        assert!(Vector::from(&[1., f64::NAN, 1., 1.]).norm_2().is_nan());
+        assert_eq!(
+            Vector::from(&[1., f64::INFINITY, 1., 1.]).norm_2(),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn matrix_norms_work() {
+        let m = Matrix::from(&[&[1., -2.], &[-3., 4.]]);
+        assert_eq!(m.norm_frobenius(), 30f64.sqrt());
+        assert_eq!(m.norm_1(), 6.);
+        assert_eq!(m.norm_inf(), 7.);
+        assert_eq!(m.norm_max(), 4.);
+        assert!((m.norm_spectral_estimate(100) - 5.464985704219043).abs() < 1e-9);
+    }
+
+    #[test]
+    fn matrix_norms_are_overflow_safe() {
+        let m = Matrix::from(&[&[1e300, -2e300], &[-3e300, 4e300]]);
+        assert!((m.norm_frobenius() / 1e300 - 30f64.sqrt()).abs() < 1e-12);
+        assert!((m.norm_spectral_estimate(100) / 1e300 - 5.464985704219043).abs() < 1e-9);
+        let tiny = Matrix::from(&[&[1e-300, 0.], &[0., 1e-300]]);
+        assert_eq!(tiny.norm_frobenius(), 2f64.sqrt() * 1e-300);
+    }
+
+    #[test]
+    fn norm_kinds_agree_with_dedicated_norms() {
+        let v = Vector::from(&[1., -2., 3.]);
+        assert_eq!(v.norm(NormKind::One).unwrap(), v.norm_1());
+        assert_eq!(v.norm(NormKind::Two).unwrap(), v.norm_2());
+        assert_eq!(v.norm(NormKind::Inf).unwrap(), v.norm_inf());
+        assert_eq!(v.norm(NormKind::P(1.)).unwrap(), 6.);
+        assert_eq!(v.norm(NormKind::P(f64::INFINITY)).unwrap(), 3.);
+        let ints = Vector::from(&[3, -4]);
+        assert_eq!(ints.norm(NormKind::Two).unwrap(), 5.);
+    }
+
+    #[test]
+    fn p_norm_works() {
+        let v = Vector::from(&[3., 4.]);
+        assert!((v.norm(NormKind::P(3.)).unwrap() - 91f64.powf(1. / 3.)).abs() < 1e-12);
+        let big = Vector::from(&[3e300, 4e300]);
+        assert!((big.norm(NormKind::P(3.)).unwrap() / 1e300 - 91f64.powf(1. / 3.)).abs() < 1e-12);
+        assert!(v.norm(NormKind::P(0.5)).is_err());
+    }
+
+    #[test]
+    fn p_norm_handles_non_finite_entries() {
+        let infinite = Vector::from(&[1., f64::INFINITY, 2.]);
+        assert_eq!(infinite.norm(NormKind::P(3.)).unwrap(), f64::INFINITY);
+        let nan = Vector::from(&[f64::NAN, f64::INFINITY]);
+        assert!(nan.norm(NormKind::P(3.)).unwrap().is_nan());
+    }
+
+    #[test]
+    fn weighted_norm_works() {
+        let v = Vector::from(&[1., 2.]);
+        let weights = Vector::from(&[4., 0.25]);
+        assert!((v.norm(NormKind::WeightedL2(&weights)).unwrap() - 5f64.sqrt()).abs() < 1e-12);
+        assert!(v
+            .norm(NormKind::WeightedL2(&Vector::from(&[1., -1.])))
+            .is_err());
+        assert!(v.norm(NormKind::WeightedL2(&Vector::from(&[1.]))).is_err());
+    }
+
+    #[test]
+    fn mahalanobis_norm_works() {
+        let v = Vector::from(&[1., 1.]);
+        let m = Matrix::from(&[&[2., 1.], &[1., 2.]]);
+        assert!((v.norm(NormKind::Mahalanobis(&m)).unwrap() - 6f64.sqrt()).abs() < 1e-12);
+        let identity = Matrix::from(&[&[1., 0.], &[0., 1.]]);
+        assert_eq!(
+            v.norm(NormKind::Mahalanobis(&identity)).unwrap(),
+            2f64.sqrt()
+        );
+        let indefinite = Matrix::from(&[&[1., 2.], &[2., 1.]]);
+        assert!(v.norm(NormKind::Mahalanobis(&indefinite)).is_err());
+        let asymmetric = Matrix::from(&[&[2., 1.], &[0., 2.]]);
+        assert!(v.norm(NormKind::Mahalanobis(&asymmetric)).is_err());
+    }
+
+    #[test]
+    fn normalize_works() {
+        let v = Vector::from(&[3., 4.]);
+        assert_eq!(v.normalized().unwrap(), Vector::from(&[0.6, 0.8]));
+        let mut w = Vector::from(&[0., 0.]);
+        assert_eq!(
+            w.normalize().unwrap_err(),
+            "Cannot normalize a zero vector".to_string()
+        );
+    }
 }
@@ -12,6 +12,9 @@ pub trait Numeric:
     + std::ops::Neg<Output = Self>
     + std::ops::AddAssign
     + std::fmt::Debug
+    + Send
+    + Sync
+    + 'static
 {
     fn magnitude(self) -> f64;
 }
@@ -31,6 +34,9 @@ where
         + std::ops::AddAssign
         + std::ops::DivAssign
         + std::fmt::Debug
+        + Send
+        + Sync
+        + 'static
         + Into<f64>,
 {
     fn magnitude(self) -> f64 {
@@ -0,0 +1,444 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::{matrix::Matrix, numeric::Numeric, vector::Vector};
+
+// Lines and columns are 1-based and count characters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token<'a> {
+    Open,
+    Close,
+    Comma,
+    Semicolon,
+    Newline,
+    Number(&'a str),
+    End,
+}
+
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Open => write!(f, "`[`"),
+            Token::Close => write!(f, "`]`"),
+            Token::Comma => write!(f, "`,`"),
+            Token::Semicolon => write!(f, "`;`"),
+            Token::Newline => write!(f, "end of line"),
+            Token::Number(text) => write!(f, "`{}`", text),
+            Token::End => write!(f, "end of input"),
+        }
+    }
+}
+
+struct Positioned<'a> {
+    token: Token<'a>,
+    line: usize,
+    column: usize,
+}
+
+fn tokenize(s: &str) -> Vec<Positioned<'_>> {
+    let mut tokens = Vec::new();
+    let (mut line, mut column) = (1, 1);
+    let mut chars = s.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            '[' => Some(Token::Open),
+            ']' => Some(Token::Close),
+            ',' => Some(Token::Comma),
+            ';' => Some(Token::Semicolon),
+            '\n' => Some(Token::Newline),
+            c if c.is_whitespace() => None,
+            _ => {
+                let mut end = start + c.len_utf8();
+                let mut width = 1;
+                while let Some(&(i, next)) = chars.peek() {
+                    if next.is_whitespace() || "[],;".contains(next) {
+                        break;
+                    }
+                    end = i + next.len_utf8();
+                    width += 1;
+                    chars.next();
+                }
+                tokens.push(Positioned {
+                    token: Token::Number(&s[start..end]),
+                    line,
+                    column,
+                });
+                column += width;
+                continue;
+            }
+        };
+        if let Some(token) = token {
+            tokens.push(Positioned {
+                token,
+                line,
+                column,
+            });
+        }
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    tokens.push(Positioned {
+        token: Token::End,
+        line,
+        column,
+    });
+    tokens
+}
+
+struct Parser<'a> {
+    tokens: Vec<Positioned<'a>>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> &Token<'a> {
+        &self.tokens[self.position].token
+    }
+
+    fn next(&mut self) -> &Positioned<'a> {
+        let current = self.position;
+        if self.position + 1 < self.tokens.len() {
+            self.position += 1;
+        }
+        &self.tokens[current]
+    }
+
+    fn error_at(&self, index: usize, message: String) -> ParseError {
+        let token = &self.tokens[index];
+        ParseError {
+            line: token.line,
+            column: token.column,
+            message,
+        }
+    }
+
+    fn unexpected(&self, expected: &str) -> ParseError {
+        let message = format!("expected {}, found {}", expected, self.peek());
+        self.error_at(self.position, message)
+    }
+
+    fn skip_newlines(&mut self) {
+        while *self.peek() == Token::Newline {
+            self.next();
+        }
+    }
+
+    fn number<K: FromStr>(&mut self) -> Result<K, ParseError> {
+        let index = self.position;
+        match self.next().token {
+            Token::Number(text) => text
+                .parse()
+                .map_err(|_| self.error_at(index, format!("invalid number `{}`", text))),
+            _ => Err(self.error_at(index, "expected a number".to_string())),
+        }
+    }
+
+    // Entries separated by commas or whitespace, up to (not including) a token
+    // that ends the row.
+    fn entries<K: FromStr>(&mut self, allow_newlines: bool) -> Result<Vec<K>, ParseError> {
+        let mut row = Vec::new();
+        loop {
+            if allow_newlines {
+                self.skip_newlines();
+            }
+            match self.peek() {
+                Token::Number(_) => row.push(self.number()?),
+                _ => return Ok(row),
+            }
+            if allow_newlines {
+                self.skip_newlines();
+            }
+            if *self.peek() == Token::Comma {
+                self.next();
+                if allow_newlines {
+                    self.skip_newlines();
+                }
+                if !matches!(self.peek(), Token::Number(_)) {
+                    return Err(self.unexpected("a number"));
+                }
+            }
+        }
+    }
+
+    fn expect(&mut self, token: Token, expected: &str) -> Result<(), ParseError> {
+        if *self.peek() != token {
+            return Err(self.unexpected(expected));
+        }
+        self.next();
+        Ok(())
+    }
+
+    fn end(&mut self) -> Result<(), ParseError> {
+        self.skip_newlines();
+        if *self.peek() != Token::End {
+            return Err(self.unexpected("end of input"));
+        }
+        Ok(())
+    }
+
+    // [[1, 2], [3, 4]]
+    fn nested<K: FromStr>(&mut self) -> Result<Vec<(usize, Vec<K>)>, ParseError> {
+        self.expect(Token::Open, "`[`")?;
+        let mut rows = Vec::new();
+        loop {
+            self.skip_newlines();
+            if *self.peek() == Token::Close {
+                self.next();
+                break;
+            }
+            let start = self.position;
+            self.expect(Token::Open, "`[`")?;
+            let row = self.entries(true)?;
+            self.expect(Token::Close, "`]`")?;
+            rows.push((start, row));
+            self.skip_newlines();
+            match self.peek() {
+                Token::Comma => {
+                    self.next();
+                }
+                Token::Close => {}
+                _ => return Err(self.unexpected("`,` or `]`")),
+            }
+        }
+        self.end()?;
+        Ok(rows)
+    }
+
+    // [1 2; 3 4], [1, 2, 3], bare rows separated by newlines or semicolons, or
+    // one bracketed row per line.
+    fn flat<K: FromStr>(&mut self) -> Result<Vec<(usize, Vec<K>)>, ParseError> {
+        let bracketed = *self.peek() == Token::Open;
+        if bracketed {
+            self.next();
+        }
+        let mut rows = Vec::new();
+        loop {
+            let start = self.position;
+            let row = self.entries(false)?;
+            if !row.is_empty() {
+                rows.push((start, row));
+            }
+            match self.peek() {
+                Token::Semicolon | Token::Newline => {
+                    self.next();
+                }
+                Token::Close if bracketed => {
+                    self.next();
+                    // Display output: one bracketed row per line.
+                    let before = self.position;
+                    self.skip_newlines();
+                    if self.position > before && *self.peek() == Token::Open {
+                        self.next();
+                        continue;
+                    }
+                    break;
+                }
+                Token::End if !bracketed => break,
+                _ if bracketed => return Err(self.unexpected("a number, `;` or `]`")),
+                _ => return Err(self.unexpected("a number or `;`")),
+            }
+        }
+        self.end()?;
+        Ok(rows)
+    }
+}
+
+fn parse_rows<K: Numeric + FromStr>(s: &str) -> Result<Vec<Vec<K>>, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(s),
+        position: 0,
+    };
+    parser.skip_newlines();
+    let first = parser.position;
+    let nested = parser.tokens[first].token == Token::Open
+        && parser.tokens[first + 1..]
+            .iter()
+            .find(|t| t.token != Token::Newline)
+            .is_some_and(|t| t.token == Token::Open);
+    let rows = if nested {
+        parser.nested()?
+    } else {
+        parser.flat()?
+    };
+    if let Some((_, first_row)) = rows.first() {
+        for (index, (start, row)) in rows.iter().enumerate() {
+            if row.len() != first_row.len() {
+                return Err(parser.error_at(
+                    *start,
+                    format!(
+                        "row {} has {} entries, expected {}",
+                        index + 1,
+                        row.len(),
+                        first_row.len()
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(rows.into_iter().map(|(_, row)| row).collect())
+}
+
+impl<K: Numeric + FromStr> FromStr for Matrix<K> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Ok(Matrix {
+            values: parse_rows(s)?,
+        })
+    }
+}
+
+// Accepts a single row or a single column.
+impl<K: Numeric + FromStr> FromStr for Vector<K> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let rows: Vec<Vec<K>> = parse_rows(s)?;
+        match rows.len() {
+            0 => Ok(Vector { values: Vec::new() }),
+            1 => Ok(Vector {
+                values: rows.into_iter().next().unwrap(),
+            }),
+            _ if rows[0].len() == 1 => Ok(Vector {
+                values: rows.into_iter().flatten().collect(),
+            }),
+            n => Err(ParseError {
+                line: 1,
+                column: 1,
+                message: format!(
+                    "expected a single row or column, found a {}x{} matrix",
+                    n,
+                    rows[0].len()
+                ),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(line: usize, column: usize, message: &str) -> ParseError {
+        ParseError {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_vectors() {
+        let expected = Vector::from(&[1., 2.5, -3.]);
+        assert_eq!("[1, 2.5, -3]".parse(), Ok(expected.clone()));
+        assert_eq!("1 2.5 -3".parse(), Ok(expected.clone()));
+        assert_eq!("[1; 2.5; -3]".parse(), Ok(expected.clone()));
+        assert_eq!("1\n2.5\n-3\n".parse(), Ok(expected));
+        assert_eq!("[]".parse(), Ok(Vector::<i32>::zeros(0)));
+        assert_eq!(
+            "[1 2; 3 4]".parse::<Vector<i32>>(),
+            Err(error(
+                1,
+                1,
+                "expected a single row or column, found a 2x2 matrix"
+            ))
+        );
+    }
+
+    #[test]
+    fn parses_matrices() {
+        let expected = Matrix::from(&[&[1, 2], &[3, 4]]);
+        assert_eq!("[[1,2],[3,4]]".parse(), Ok(expected.clone()));
+        assert_eq!("[\n  [1, 2],\n  [3, 4],\n]".parse(), Ok(expected.clone()));
+        assert_eq!("[1 2; 3 4]".parse(), Ok(expected.clone()));
+        assert_eq!("[1, 2;\n 3, 4]".parse(), Ok(expected.clone()));
+        assert_eq!("1 2\n3 4\n".parse(), Ok(expected.clone()));
+        assert_eq!("\n\t1\t2\r\n\n3 4".parse(), Ok(expected));
+        assert_eq!("[1 2 3]".parse(), Ok(Matrix::from(&[&[1, 2, 3]])));
+    }
+
+    #[test]
+    fn parsed_matrices_display_round_trip() {
+        let m = Matrix::from(&[&[1.5, -2.], &[3., 40.]]);
+        assert_eq!(m.to_string().parse(), Ok(m.clone()));
+        assert_eq!(
+            "[1.5, -2]\n[3, 40]\n[5 6]"
+                .parse::<Matrix<f64>>()
+                .unwrap()
+                .values
+                .len(),
+            3
+        );
+        assert!("[1.5, -2] [3, 40]".parse::<Matrix<f64>>().is_err());
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert_eq!(
+            "[1 2; 3]".parse::<Matrix<i32>>(),
+            Err(error(1, 7, "row 2 has 1 entries, expected 2"))
+        );
+        assert_eq!(
+            "[[1, 2],\n [3, 4, 5]]".parse::<Matrix<i32>>(),
+            Err(error(2, 2, "row 2 has 3 entries, expected 2"))
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_problem() {
+        assert_eq!(
+            "[1 2; 3 x4]".parse::<Matrix<f64>>(),
+            Err(error(1, 9, "invalid number `x4`"))
+        );
+        assert_eq!(
+            "1 2\n3 4.4.4".parse::<Matrix<f64>>(),
+            Err(error(2, 3, "invalid number `4.4.4`"))
+        );
+        assert_eq!(
+            "[1, 2".parse::<Matrix<i32>>(),
+            Err(error(
+                1,
+                6,
+                "expected a number, `;` or `]`, found end of input"
+            ))
+        );
+        assert_eq!(
+            "[1, , 2]".parse::<Matrix<i32>>(),
+            Err(error(1, 5, "expected a number, found `,`"))
+        );
+        assert_eq!(
+            "[[1, 2] [3, 4]]".parse::<Matrix<i32>>(),
+            Err(error(1, 9, "expected `,` or `]`, found `[`"))
+        );
+        assert_eq!(
+            "[1 2] 3".parse::<Matrix<i32>>(),
+            Err(error(1, 7, "expected end of input, found `3`"))
+        );
+        assert_eq!(
+            "[1 2] 3".parse::<Matrix<i32>>().unwrap_err().to_string(),
+            "line 1, column 7: expected end of input, found `3`"
+        );
+    }
+}
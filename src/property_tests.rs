@@ -0,0 +1,231 @@
+use proptest::prelude::*;
+
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{linear_interpolation::Interpolate, matrix::Matrix, random, vector::Vector};
+
+// Integer entries keep products exact, so identities can be checked with ==.
+fn integer_matrix(rows: usize, cols: usize) -> impl Strategy<Value = Matrix<i32>> {
+    prop::collection::vec(prop::collection::vec(-50i32..=50, cols), rows)
+        .prop_map(|values| Matrix { values })
+}
+
+// Driven by `random::integer_with_determinant` from a seed drawn by proptest.
+// Sizes stay small enough that exact elimination cannot overflow i32.
+fn integer_with_determinant(n: usize) -> impl Strategy<Value = (Matrix<i32>, i32)> {
+    (any::<u64>(), -3i32..=3).prop_map(move |(seed, determinant)| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (
+            random::integer_with_determinant(&mut rng, n, determinant),
+            determinant,
+        )
+    })
+}
+
+fn float_vector(len: usize) -> impl Strategy<Value = Vector<f64>> {
+    prop::collection::vec(-1e3f64..1e3, len).prop_map(|values| Vector { values })
+}
+
+// Strictly diagonally dominant, hence invertible with a modest condition number.
+fn well_conditioned_of_size(n: usize) -> impl Strategy<Value = Matrix<f64>> {
+    (
+        prop::collection::vec(prop::collection::vec(-1f64..1., n), n),
+        prop::collection::vec(prop::bool::ANY, n),
+    )
+        .prop_map(move |(mut values, signs)| {
+            for (i, row) in values.iter_mut().enumerate() {
+                let magnitude = n as f64 + row[i].abs();
+                row[i] = if signs[i] { magnitude } else { -magnitude };
+            }
+            Matrix { values }
+        })
+}
+
+fn well_conditioned(max_size: usize) -> impl Strategy<Value = Matrix<f64>> {
+    (1..=max_size).prop_flat_map(well_conditioned_of_size)
+}
+
+fn float_matrix(rows: usize, cols: usize) -> impl Strategy<Value = Matrix<f64>> {
+    prop::collection::vec(prop::collection::vec(-1e3f64..1e3, cols), rows)
+        .prop_map(|values| Matrix { values })
+}
+
+// Random rows plus copies of them scaled by powers of two and zero rows, in a
+// random order, so the rank is known.
+fn degenerate_with_shape(rows: usize, cols: usize) -> impl Strategy<Value = (Matrix<f64>, usize)> {
+    (1..rows)
+        .prop_flat_map(move |independent| {
+            (
+                prop::collection::vec(prop::collection::vec(-1f64..1., cols), independent),
+                prop::collection::vec(
+                    prop::option::of((0..independent, -3i32..=3)),
+                    rows - independent,
+                ),
+            )
+        })
+        .prop_flat_map(move |(base, copies)| {
+            let mut values = base.clone();
+            for copy in copies {
+                values.push(match copy {
+                    Some((i, exponent)) => {
+                        base[i].iter().map(|x| x * 2f64.powi(exponent)).collect()
+                    }
+                    None => vec![0.; cols],
+                });
+            }
+            let rank = base.len().min(cols);
+            (Just(values).prop_shuffle(), Just(rank))
+        })
+        .prop_map(|(values, rank)| (Matrix { values }, rank))
+}
+
+fn degenerate(max_size: usize) -> impl Strategy<Value = (Matrix<f64>, usize)> {
+    (2..=max_size, 1..=max_size).prop_flat_map(|(rows, cols)| degenerate_with_shape(rows, cols))
+}
+
+fn degenerate_square(max_size: usize) -> impl Strategy<Value = Matrix<f64>> {
+    (2..=max_size).prop_flat_map(|n| degenerate_with_shape(n, n).prop_map(|(a, _)| a))
+}
+
+fn transposed<K: crate::numeric::Numeric>(m: &Matrix<K>) -> Matrix<K> {
+    m.clone().transpose()
+}
+
+proptest! {
+    #[test]
+    fn transpose_of_product_is_reversed_product(
+        (a, b) in (1usize..6, 1usize..6, 1usize..6)
+            .prop_flat_map(|(n, m, p)| (integer_matrix(n, m), integer_matrix(m, p)))
+    ) {
+        let product = a.mul_mat_naive(&b);
+        prop_assert_eq!(transposed(&product), transposed(&b).mul_mat_naive(&transposed(&a)));
+    }
+
+    // Both sides add the same products in the same order, so even floats match exactly.
+    #[test]
+    fn float_transpose_of_product_is_reversed_product(
+        (a, b) in (1usize..6, 1usize..6, 1usize..6)
+            .prop_flat_map(|(n, m, p)| (float_matrix(n, m), float_matrix(m, p)))
+    ) {
+        let product = a.mul_mat(b.clone());
+        prop_assert_eq!(transposed(&product), transposed(&b).mul_mat(transposed(&a)));
+    }
+
+    #[test]
+    fn determinant_is_multiplicative(
+        (a, b) in (1usize..6).prop_flat_map(|n| (well_conditioned_of_size(n), well_conditioned_of_size(n)))
+    ) {
+        let n = a.values.len() as i32;
+        let bound = a.norm_frobenius().powi(n) * b.norm_frobenius().powi(n);
+        let error = (a.mul_mat_naive(&b).determinant() - a.determinant() * b.determinant()).abs();
+        prop_assert!(error <= 1e-12 * bound);
+    }
+
+    #[test]
+    fn integer_determinant_is_exact(
+        (a, determinant) in (1usize..=4).prop_flat_map(integer_with_determinant)
+    ) {
+        prop_assert_eq!(a.determinant(), determinant);
+        prop_assert_eq!(transposed(&a).determinant(), determinant);
+    }
+
+    #[test]
+    fn integer_determinant_is_multiplicative(
+        ((a, det_a), (b, det_b)) in (1usize..=3)
+            .prop_flat_map(|n| (integer_with_determinant(n), integer_with_determinant(n)))
+    ) {
+        prop_assert_eq!(a.mul_mat_naive(&b).determinant(), det_a * det_b);
+    }
+
+    #[test]
+    fn float_determinant_agrees_with_integer_one(
+        (a, determinant) in (1usize..=4).prop_flat_map(integer_with_determinant)
+    ) {
+        let a = Matrix {
+            values: a
+                .values
+                .iter()
+                .map(|row| row.iter().map(|&x| x as f64).collect())
+                .collect(),
+        };
+        prop_assert!((a.determinant() - determinant as f64).abs() <= 1e-9);
+    }
+
+    #[test]
+    fn integer_row_echelon_ends_in_the_determinant(
+        (a, determinant) in (1usize..=4).prop_flat_map(integer_with_determinant)
+    ) {
+        let n = a.values.len();
+        let echelon = a.row_echelon();
+        for (i, row) in echelon.values.iter().enumerate() {
+            prop_assert!(row[..i.min(n)].iter().all(|&x| x == 0));
+        }
+        prop_assert_eq!(echelon.values[n - 1][n - 1].abs(), determinant.abs());
+    }
+
+    #[test]
+    fn inverse_is_an_inverse(a in well_conditioned(6)) {
+        let n = a.values.len();
+        let product = a.mul_mat_naive(&a.inverse().unwrap());
+        for i in 0..n {
+            for j in 0..n {
+                let expected = if i == j { 1. } else { 0. };
+                prop_assert!((product.values[i][j] - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn rank_of_transpose_is_rank(a in well_conditioned(6)) {
+        prop_assert_eq!(a.rank(), a.values.len());
+        prop_assert_eq!(transposed(&a).rank(), a.values.len());
+    }
+
+    #[test]
+    fn rank_of_degenerate_matrix_is_known((a, rank) in degenerate(6)) {
+        prop_assert_eq!(a.rank(), rank);
+        prop_assert_eq!(transposed(&a).rank(), rank);
+    }
+
+    #[test]
+    fn reduced_row_echelon_is_idempotent((a, _) in degenerate(6)) {
+        let once = a.reduced_row_echelon();
+        prop_assert_eq!(once.clone().reduced_row_echelon(), once);
+    }
+
+    #[test]
+    fn determinant_of_degenerate_matrix_is_zero(a in degenerate_square(6)) {
+        let n = a.values.len() as i32;
+        prop_assert!(a.determinant().abs() <= 1e-14 * a.norm_frobenius().powi(n).max(1.));
+    }
+
+    #[test]
+    fn cauchy_schwarz_holds(
+        (u, v) in (0usize..20).prop_flat_map(|n| (float_vector(n), float_vector(n)))
+    ) {
+        let bound = u.norm_2() * v.norm_2();
+        prop_assert!(u.dot(&v).abs() <= bound * (1. + 1e-12));
+    }
+
+    #[test]
+    fn cross_product_is_orthogonal(u in float_vector(3), v in float_vector(3)) {
+        let w = u.cross_product(&v);
+        let scale = u.norm_2() * v.norm_2();
+        prop_assert!(w.dot(&u).abs() <= 1e-12 * scale * u.norm_2());
+        prop_assert!(w.dot(&v).abs() <= 1e-12 * scale * v.norm_2());
+    }
+
+    #[test]
+    fn lerp_hits_end_points_and_stays_between_them(
+        (u, v) in (0usize..10).prop_flat_map(|n| (float_vector(n), float_vector(n))),
+        t in 0f64..=1.
+    ) {
+        prop_assert_eq!(Vector::lerp(u.clone(), v.clone(), 0.), u.clone());
+        prop_assert_eq!(Vector::lerp(u.clone(), v.clone(), 1.), v.clone());
+        let mid = Vector::lerp(u.clone(), v.clone(), t);
+        for ((m, a), b) in mid.values.iter().zip(&u.values).zip(&v.values) {
+            let slack = 1e-12 * (a.abs() + b.abs());
+            prop_assert!(*m >= a.min(*b) - slack && *m <= a.max(*b) + slack);
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use std::ops::{Mul, Neg};
+
+use num::Float;
+
+use crate::{matrix::Matrix, numeric::Numeric, vector::Vector};
+
+// w + xi + yj + zk. Rotations are represented by unit quaternions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion<K: Numeric + Float> {
+    pub w: K,
+    pub x: K,
+    pub y: K,
+    pub z: K,
+}
+
+fn two<K: Numeric + Float>() -> K {
+    K::one() + K::one()
+}
+
+impl<K: Numeric + Float> Quaternion<K> {
+    pub fn new(w: K, x: K, y: K, z: K) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    pub fn identity() -> Self {
+        Quaternion::new(K::one(), K::zero(), K::zero(), K::zero())
+    }
+
+    pub fn from_axis_angle(axis: &Vector<K>, angle: K) -> Self {
+        if axis.values.len() != 3 {
+            panic!("Rotation axis must have length 3");
+        }
+        let axis = axis
+            .normalized()
+            .unwrap_or_else(|_| panic!("Rotation axis must not be zero"));
+        let (s, c) = (angle / two()).sin_cos();
+        Quaternion::new(
+            c,
+            axis.values[0] * s,
+            axis.values[1] * s,
+            axis.values[2] * s,
+        )
+    }
+
+    // Returns a unit axis and an angle in [0, pi]; the identity rotation gets the x axis.
+    pub fn to_axis_angle(&self) -> (Vector<K>, K) {
+        let q = self.normalized().unwrap_or_else(|e| panic!("{}", e));
+        let q = if q.w < K::zero() { -q } else { q };
+        let sin_half = (q.x * q.x + q.y * q.y + q.z * q.z).sqrt();
+        if sin_half == K::zero() {
+            return (Vector::from(&[K::one(), K::zero(), K::zero()]), K::zero());
+        }
+        let axis = Vector::from(&[q.x / sin_half, q.y / sin_half, q.z / sin_half]);
+        (axis, two::<K>() * sin_half.atan2(q.w))
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn dot(&self, other: &Quaternion<K>) -> K {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn norm(&self) -> K {
+        Vector::from(&[self.w, self.x, self.y, self.z]).norm_2()
+    }
+
+    pub fn inverse(&self) -> Result<Self, String> {
+        let norm_squared = self.dot(self);
+        if norm_squared == K::zero() {
+            return Err("Quaternion is not invertible".to_string());
+        }
+        let c = self.conjugate();
+        Ok(Quaternion::new(
+            c.w / norm_squared,
+            c.x / norm_squared,
+            c.y / norm_squared,
+            c.z / norm_squared,
+        ))
+    }
+
+    pub fn normalize(&mut self) -> Result<(), String> {
+        let norm = self.norm();
+        if norm == K::zero() {
+            return Err("Cannot normalize a zero quaternion".to_string());
+        }
+        *self = Quaternion::new(self.w / norm, self.x / norm, self.y / norm, self.z / norm);
+        Ok(())
+    }
+
+    pub fn normalized(&self) -> Result<Self, String> {
+        let mut result = *self;
+        result.normalize()?;
+        Ok(result)
+    }
+
+    // Computes q v q^-1, so non-unit quaternions rotate without scaling.
+    pub fn rotate(&self, v: &Vector<K>) -> Vector<K> {
+        if v.values.len() != 3 {
+            panic!("Can only rotate vectors of length 3");
+        }
+        let p = Quaternion::new(K::zero(), v.values[0], v.values[1], v.values[2]);
+        let inverse = self.inverse().unwrap_or_else(|e| panic!("{}", e));
+        let r = *self * p * inverse;
+        Vector::from(&[r.x, r.y, r.z])
+    }
+
+    pub fn to_rotation_matrix(&self) -> Matrix<K> {
+        let q = self.normalized().unwrap_or_else(|e| panic!("{}", e));
+        let (w, x, y, z) = (q.w, q.x, q.y, q.z);
+        let one = K::one();
+        let two = two::<K>();
+        Matrix {
+            values: vec![
+                vec![
+                    one - two * (y * y + z * z),
+                    two * (x * y - w * z),
+                    two * (x * z + w * y),
+                ],
+                vec![
+                    two * (x * y + w * z),
+                    one - two * (x * x + z * z),
+                    two * (y * z - w * x),
+                ],
+                vec![
+                    two * (x * z - w * y),
+                    two * (y * z + w * x),
+                    one - two * (x * x + y * y),
+                ],
+            ],
+        }
+    }
+
+    pub fn to_homogeneous_matrix(&self) -> Matrix<K> {
+        let mut m = self.to_rotation_matrix();
+        for row in m.values.iter_mut() {
+            row.push(K::zero());
+        }
+        m.values
+            .push(vec![K::zero(), K::zero(), K::zero(), K::one()]);
+        m
+    }
+
+    // Accepts a 3x3 rotation or a 4x4 transform whose upper-left block is one.
+    // Uses Shepperd's method: the largest of w, x, y, z is recovered from the
+    // diagonal first so that the division below is well conditioned.
+    pub fn from_rotation_matrix(m: &Matrix<K>) -> Result<Self, String> {
+        let n = m.values.len();
+        if !(n == 3 || n == 4) || m.values.iter().any(|row| row.len() != n) {
+            return Err("Rotation matrix must be 3x3 or 4x4".to_string());
+        }
+        let r = |i: usize, j: usize| m.values[i][j];
+        let tolerance = K::epsilon().sqrt();
+        for i in 0..3 {
+            for j in 0..3 {
+                let dot = (0..3).fold(K::zero(), |acc, k| acc + r(k, i) * r(k, j));
+                let expected = if i == j { K::one() } else { K::zero() };
+                if (dot - expected).abs() > tolerance {
+                    return Err("Matrix is not a rotation".to_string());
+                }
+            }
+        }
+        let determinant = r(0, 0) * (r(1, 1) * r(2, 2) - r(1, 2) * r(2, 1))
+            - r(0, 1) * (r(1, 0) * r(2, 2) - r(1, 2) * r(2, 0))
+            + r(0, 2) * (r(1, 0) * r(2, 1) - r(1, 1) * r(2, 0));
+        if determinant < K::zero() {
+            return Err("Matrix is not a rotation".to_string());
+        }
+        let one = K::one();
+        let quarter = one / (two::<K>() * two::<K>());
+        let trace = r(0, 0) + r(1, 1) + r(2, 2);
+        let q = if trace > r(0, 0).max(r(1, 1)).max(r(2, 2)) {
+            let s = two::<K>() * (one + trace).sqrt();
+            Quaternion::new(
+                quarter * s,
+                (r(2, 1) - r(1, 2)) / s,
+                (r(0, 2) - r(2, 0)) / s,
+                (r(1, 0) - r(0, 1)) / s,
+            )
+        } else if r(0, 0) >= r(1, 1) && r(0, 0) >= r(2, 2) {
+            let s = two::<K>() * (one + r(0, 0) - r(1, 1) - r(2, 2)).sqrt();
+            Quaternion::new(
+                (r(2, 1) - r(1, 2)) / s,
+                quarter * s,
+                (r(0, 1) + r(1, 0)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+            )
+        } else if r(1, 1) >= r(2, 2) {
+            let s = two::<K>() * (one + r(1, 1) - r(0, 0) - r(2, 2)).sqrt();
+            Quaternion::new(
+                (r(0, 2) - r(2, 0)) / s,
+                (r(0, 1) + r(1, 0)) / s,
+                quarter * s,
+                (r(1, 2) + r(2, 1)) / s,
+            )
+        } else {
+            let s = two::<K>() * (one + r(2, 2) - r(0, 0) - r(1, 1)).sqrt();
+            Quaternion::new(
+                (r(1, 0) - r(0, 1)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+                (r(1, 2) + r(2, 1)) / s,
+                quarter * s,
+            )
+        };
+        q.normalized()
+    }
+
+    // Normalised linear interpolation along the shorter arc. Cheaper than slerp
+    // but does not move at constant angular velocity.
+    pub fn nlerp(a: &Quaternion<K>, b: &Quaternion<K>, t: K) -> Self {
+        let b = if a.dot(b) < K::zero() { -*b } else { *b };
+        let s = K::one() - t;
+        Quaternion::new(
+            a.w * s + b.w * t,
+            a.x * s + b.x * t,
+            a.y * s + b.y * t,
+            a.z * s + b.z * t,
+        )
+        .normalized()
+        .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // Spherical linear interpolation along the shorter arc between unit quaternions.
+    pub fn slerp(a: &Quaternion<K>, b: &Quaternion<K>, t: K) -> Self {
+        let a = a.normalized().unwrap_or_else(|e| panic!("{}", e));
+        let mut b = b.normalized().unwrap_or_else(|e| panic!("{}", e));
+        let mut cos = a.dot(&b);
+        if cos < K::zero() {
+            b = -b;
+            cos = -cos;
+        }
+        // Nearly identical orientations: sin(theta) ~ 0, so fall back to nlerp.
+        if cos > K::one() - K::epsilon().sqrt() {
+            return Quaternion::nlerp(&a, &b, t);
+        }
+        let theta = cos.min(K::one()).acos();
+        let sin = theta.sin();
+        let wa = ((K::one() - t) * theta).sin() / sin;
+        let wb = (t * theta).sin() / sin;
+        Quaternion::new(
+            a.w * wa + b.w * wb,
+            a.x * wa + b.x * wb,
+            a.y * wa + b.y * wb,
+            a.z * wa + b.z * wb,
+        )
+    }
+}
+
+impl<K: Numeric + Float> Mul for Quaternion<K> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Quaternion::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+impl<K: Numeric + Float> Neg for Quaternion<K> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_3, PI};
+
+    fn assert_close(a: &Quaternion<f64>, b: &Quaternion<f64>) {
+        assert!(
+            (a.w - b.w).abs() < 1e-12
+                && (a.x - b.x).abs() < 1e-12
+                && (a.y - b.y).abs() < 1e-12
+                && (a.z - b.z).abs() < 1e-12,
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    fn assert_vector_close(a: &Vector<f64>, b: &[f64]) {
+        for (x, y) in a.values.iter().zip(b) {
+            assert!((x - y).abs() < 1e-12, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn hamilton_product_works() {
+        let i = Quaternion::new(0., 1., 0., 0.);
+        let j = Quaternion::new(0., 0., 1., 0.);
+        let k = Quaternion::new(0., 0., 0., 1.);
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+        assert_eq!(i * j * k, Quaternion::new(-1., 0., 0., 0.));
+        let q = Quaternion::new(1., 2., 3., 4.);
+        assert_eq!(q * Quaternion::identity(), q);
+    }
+
+    #[test]
+    fn conjugate_inverse_and_normalize_work() {
+        let q = Quaternion::new(1., 2., 3., 4.);
+        assert_eq!(q.conjugate(), Quaternion::new(1., -2., -3., -4.));
+        assert_close(&(q * q.inverse().unwrap()), &Quaternion::identity());
+        assert!((q.normalized().unwrap().norm() - 1.).abs() < 1e-15);
+        let zero = Quaternion::new(0., 0., 0., 0.);
+        assert!(zero.inverse().is_err());
+        assert!(zero.normalized().is_err());
+    }
+
+    #[test]
+    fn rotate_works() {
+        let q = Quaternion::from_axis_angle(&Vector::from(&[0., 0., 1.]), FRAC_PI_2);
+        assert_vector_close(&q.rotate(&Vector::from(&[1., 0., 0.])), &[0., 1., 0.]);
+        let scaled = Quaternion::new(q.w * 3., q.x * 3., q.y * 3., q.z * 3.);
+        assert_vector_close(&scaled.rotate(&Vector::from(&[1., 0., 0.])), &[0., 1., 0.]);
+    }
+
+    #[test]
+    fn axis_angle_round_trips() {
+        let axis = Vector::from(&[1., 2., 2.]);
+        let q = Quaternion::from_axis_angle(&axis, 2.);
+        let (round_axis, angle) = q.to_axis_angle();
+        assert_vector_close(&round_axis, &[1. / 3., 2. / 3., 2. / 3.]);
+        assert!((angle - 2.).abs() < 1e-12);
+        let (_, angle) = Quaternion::<f64>::identity().to_axis_angle();
+        assert_eq!(angle, 0.);
+    }
+
+    #[test]
+    fn rotation_matrices_round_trip() {
+        let q = Quaternion::from_axis_angle(&Vector::from(&[0., 0., 1.]), FRAC_PI_2);
+        assert_eq!(
+            q.to_rotation_matrix()
+                .values
+                .iter()
+                .flatten()
+                .map(|x| x.round())
+                .collect::<Vec<_>>(),
+            vec![0., -1., 0., 1., 0., 0., 0., 0., 1.]
+        );
+        for (axis, angle) in [
+            ([1., 2., 3.], 0.3),
+            ([1., 0., 0.], PI),
+            ([0., 1., 0.], PI),
+            ([0., 0., 1.], PI),
+            ([-1., 1., 0.5], 3.),
+        ] {
+            let q = Quaternion::from_axis_angle(&Vector::from(&axis), angle);
+            let from_3 = Quaternion::from_rotation_matrix(&q.to_rotation_matrix()).unwrap();
+            let from_4 = Quaternion::from_rotation_matrix(&q.to_homogeneous_matrix()).unwrap();
+            // q and -q describe the same rotation.
+            let sign = if from_3.dot(&q) < 0. { -1. } else { 1. };
+            assert_close(&(from_3 * Quaternion::new(sign, 0., 0., 0.)), &q);
+            assert_close(&from_3, &from_4);
+        }
+        let reflection = Matrix::from(&[&[1., 0., 0.], &[0., 1., 0.], &[0., 0., -1.]]);
+        assert!(Quaternion::from_rotation_matrix(&reflection).is_err());
+        let skewed = Matrix::from(&[&[1., 1., 0.], &[0., 1., 0.], &[0., 0., 1.]]);
+        assert!(Quaternion::from_rotation_matrix(&skewed).is_err());
+    }
+
+    #[test]
+    fn slerp_moves_at_constant_angular_velocity() {
+        let z = Vector::from(&[0., 0., 1.]);
+        let a = Quaternion::identity();
+        let b = Quaternion::from_axis_angle(&z, FRAC_PI_2);
+        let mid = Quaternion::slerp(&a, &b, 1. / 3.);
+        assert_close(&mid, &Quaternion::from_axis_angle(&z, FRAC_PI_2 / 3.));
+        assert_close(&Quaternion::slerp(&a, &b, 0.), &a);
+        assert_close(&Quaternion::slerp(&a, &b, 1.), &b);
+        // -b is the same rotation, and slerp takes the short way round.
+        assert_close(
+            &Quaternion::slerp(&a, &-b, 0.5),
+            &Quaternion::from_axis_angle(&z, FRAC_PI_2 / 2.),
+        );
+    }
+
+    #[test]
+    fn nlerp_works() {
+        let z = Vector::from(&[0., 0., 1.]);
+        let a = Quaternion::from_axis_angle(&z, -FRAC_PI_3);
+        let b = Quaternion::from_axis_angle(&z, FRAC_PI_3);
+        assert_close(&Quaternion::nlerp(&a, &b, 0.5), &Quaternion::identity());
+        assert!((Quaternion::nlerp(&a, &b, 0.2).norm() - 1.).abs() < 1e-15);
+    }
+}
@@ -0,0 +1,272 @@
+use num::Float;
+use rand::Rng;
+
+use crate::{
+    basis::{gram_schmidt_with, GramSchmidt},
+    matrix::Matrix,
+    numeric::Numeric,
+    sparse::{CooMatrix, CsrMatrix},
+    vector::Vector,
+};
+
+// Every generator takes the random number generator explicitly, so seeding it
+// (e.g. `StdRng::seed_from_u64`) makes the output reproducible.
+
+fn convert<K: Numeric + Float>(x: f64) -> K {
+    K::from(x).unwrap()
+}
+
+fn sample_uniform<K: Numeric + Float, R: Rng + ?Sized>(rng: &mut R, low: K, high: K) -> K {
+    if low.is_nan() || high.is_nan() || low >= high {
+        panic!("Lower bound must be below the upper bound");
+    }
+    low + (high - low) * convert::<K>(rng.gen::<f64>())
+}
+
+// Box-Muller transform; 1 - gen() lies in (0, 1], so the logarithm is finite.
+fn sample_standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    let u1 = 1. - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    (-2. * u1.ln()).sqrt() * (2. * std::f64::consts::PI * u2).cos()
+}
+
+fn sample_normal<K: Numeric + Float, R: Rng + ?Sized>(rng: &mut R, mean: K, std_dev: K) -> K {
+    if std_dev < K::zero() {
+        panic!("Standard deviation must be non-negative");
+    }
+    mean + std_dev * convert::<K>(sample_standard_normal(rng))
+}
+
+pub fn uniform_vector<K: Numeric + Float, R: Rng + ?Sized>(
+    rng: &mut R,
+    n: usize,
+    low: K,
+    high: K,
+) -> Vector<K> {
+    Vector {
+        values: (0..n).map(|_| sample_uniform(rng, low, high)).collect(),
+    }
+}
+
+pub fn uniform_matrix<K: Numeric + Float, R: Rng + ?Sized>(
+    rng: &mut R,
+    rows: usize,
+    cols: usize,
+    low: K,
+    high: K,
+) -> Matrix<K> {
+    Matrix::from_fn(rows, cols, |_, _| sample_uniform(rng, low, high))
+}
+
+pub fn normal_vector<K: Numeric + Float, R: Rng + ?Sized>(
+    rng: &mut R,
+    n: usize,
+    mean: K,
+    std_dev: K,
+) -> Vector<K> {
+    Vector {
+        values: (0..n).map(|_| sample_normal(rng, mean, std_dev)).collect(),
+    }
+}
+
+pub fn normal_matrix<K: Numeric + Float, R: Rng + ?Sized>(
+    rng: &mut R,
+    rows: usize,
+    cols: usize,
+    mean: K,
+    std_dev: K,
+) -> Matrix<K> {
+    Matrix::from_fn(rows, cols, |_, _| sample_normal(rng, mean, std_dev))
+}
+
+// Haar-distributed orthogonal matrix: the Q factor of a Gaussian matrix, taken
+// with a positive diagonal in R, which Gram-Schmidt produces by construction.
+pub fn orthogonal<K: Numeric + Float, R: Rng + ?Sized>(rng: &mut R, n: usize) -> Matrix<K> {
+    loop {
+        let columns: Vec<Vector<K>> = (0..n)
+            .map(|_| normal_vector(rng, n, K::zero(), K::one()))
+            .collect();
+        let q = gram_schmidt_with(&columns, GramSchmidt::Reorthogonalized);
+        // A rank deficient Gaussian sample has probability zero, but retry anyway.
+        if q.len() == n {
+            return Matrix::from_columns(&q);
+        }
+    }
+}
+
+// Q diag(1, ..., condition) Q^T with Haar Q and eigenvalues spaced
+// geometrically, so the 2-norm condition number is exactly `condition`.
+pub fn spd_with_condition<K: Numeric + Float, R: Rng + ?Sized>(
+    rng: &mut R,
+    n: usize,
+    condition: K,
+) -> Matrix<K> {
+    if condition.is_nan() || condition < K::one() || !condition.is_finite() {
+        panic!("Condition number must be finite and at least 1");
+    }
+    let q = orthogonal::<K, R>(rng, n);
+    let eigenvalues: Vec<K> = (0..n)
+        .map(|i| {
+            if n == 1 {
+                K::one()
+            } else {
+                condition.powf(convert::<K>(i as f64 / (n - 1) as f64))
+            }
+        })
+        .collect();
+    let mut result = Matrix::from_fn(n, n, |i, j| {
+        (0..n).fold(K::zero(), |acc, k| {
+            acc + q.values[i][k] * eigenvalues[k] * q.values[j][k]
+        })
+    });
+    // Symmetrise exactly; rounding above can leave the two halves a few ulps apart.
+    for i in 0..n {
+        for j in 0..i {
+            result.values[j][i] = result.values[i][j];
+        }
+    }
+    result
+}
+
+// Each entry is non-zero with probability `density`, with values uniform in [-1, 1).
+pub fn sparse<K: Numeric + Float, R: Rng + ?Sized>(
+    rng: &mut R,
+    rows: usize,
+    cols: usize,
+    density: f64,
+) -> CsrMatrix<K> {
+    if !(0. ..=1.).contains(&density) {
+        panic!("Density must lie in [0, 1]");
+    }
+    let mut coo = CooMatrix::new(rows, cols);
+    for i in 0..rows {
+        for j in 0..cols {
+            if rng.gen_bool(density) {
+                let value = sample_uniform(rng, -K::one(), K::one());
+                if value != K::zero() {
+                    coo.push(i, j, value);
+                }
+            }
+        }
+    }
+    coo.to_csr()
+}
+
+// P L D U with D = diag(determinant, 1, ..., 1), unit triangular L and U with
+// small random entries and a random permutation P whose sign is compensated,
+// so the result has exactly the requested determinant.
+pub fn integer_with_determinant<R: Rng + ?Sized>(
+    rng: &mut R,
+    n: usize,
+    determinant: i32,
+) -> Matrix<i32> {
+    if n == 0 {
+        panic!("Matrix must have at least one row");
+    }
+    let mut unit_triangular = |lower: bool| {
+        Matrix::from_fn(n, n, |i, j| {
+            if i == j {
+                1
+            } else if (i > j) == lower {
+                rng.gen_range(-2..=2)
+            } else {
+                0
+            }
+        })
+    };
+    let l = unit_triangular(true);
+    let u = unit_triangular(false);
+    let mut d = Matrix::identity(n);
+    d.values[0][0] = determinant;
+    let mut result = l.mul_mat(d).mul_mat(u);
+    let mut odd = false;
+    for i in (1..n).rev() {
+        let j = rng.gen_range(0..=i);
+        if i != j {
+            result.values.swap(i, j);
+            odd = !odd;
+        }
+    }
+    if odd {
+        result.values[0].iter_mut().for_each(|x| *x = -*x);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn generators_are_reproducible() {
+        let a: Matrix<f64> = uniform_matrix(&mut StdRng::seed_from_u64(7), 3, 4, -1., 1.);
+        let b: Matrix<f64> = uniform_matrix(&mut StdRng::seed_from_u64(7), 3, 4, -1., 1.);
+        assert_eq!(a, b);
+        let c: Matrix<f64> = uniform_matrix(&mut StdRng::seed_from_u64(8), 3, 4, -1., 1.);
+        assert_ne!(a, c);
+        assert!(a.values.iter().flatten().all(|&x| (-1. ..1.).contains(&x)));
+    }
+
+    #[test]
+    fn normal_samples_have_expected_moments() {
+        let v: Vector<f64> = normal_vector(&mut StdRng::seed_from_u64(1), 20000, 3., 2.);
+        let n = v.values.len() as f64;
+        let mean = v.values.iter().sum::<f64>() / n;
+        let variance = v
+            .values
+            .iter()
+            .map(|x| (x - mean) * (x - mean))
+            .sum::<f64>()
+            / n;
+        assert!((mean - 3.).abs() < 0.05);
+        assert!((variance - 4.).abs() < 0.15);
+        let m: Matrix<f32> = normal_matrix(&mut StdRng::seed_from_u64(1), 2, 2, 0., 1.);
+        assert_eq!(m.values.len(), 2);
+    }
+
+    #[test]
+    fn orthogonal_matrices_are_orthogonal() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let q: Matrix<f64> = orthogonal(&mut rng, 5);
+        let transposed = q.clone().transpose();
+        let product = q.mul_mat(transposed);
+        for (i, row) in product.values.iter().enumerate() {
+            for (j, x) in row.iter().enumerate() {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((x - expected).abs() < 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn spd_matrices_have_requested_condition() {
+        let a: Matrix<f64> = spd_with_condition(&mut StdRng::seed_from_u64(4), 6, 1e3);
+        let mut transposed = a.clone();
+        assert_eq!(transposed.transpose(), a);
+        let largest = a.norm_spectral_estimate(500);
+        let smallest = 1. / a.inverse().unwrap().norm_spectral_estimate(500);
+        assert!((largest - 1e3).abs() < 1e-6);
+        assert!((smallest - 1.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn sparse_matrices_have_requested_density() {
+        let s: CsrMatrix<f64> = sparse(&mut StdRng::seed_from_u64(5), 100, 100, 0.1);
+        assert_eq!((s.rows(), s.cols()), (100, 100));
+        assert!((800..1200).contains(&s.nnz()));
+        let empty: CsrMatrix<f64> = sparse(&mut StdRng::seed_from_u64(5), 10, 10, 0.);
+        assert_eq!(empty.nnz(), 0);
+    }
+
+    #[test]
+    fn integer_matrices_have_requested_determinant() {
+        let mut rng = StdRng::seed_from_u64(6);
+        for determinant in [1, -1, 7, -12, 0] {
+            let m = integer_with_determinant(&mut rng, 5, determinant);
+            let as_float = Matrix::from_fn(5, 5, |i, j| m.values[i][j] as f64);
+            let computed = as_float.lu().map_or(0., |lu| lu.determinant());
+            assert!((computed - determinant as f64).abs() < 1e-8);
+        }
+    }
+}
@@ -1,13 +1,55 @@
-use crate::{matrix::Matrix, numeric::Numeric};
+use crate::{condition::unit_roundoff, matrix::Matrix, numeric::Numeric};
 
 impl<K: Numeric> Matrix<K> {
-    fn rank(&self) -> usize {
+    pub fn rank(&self) -> usize {
+        let epsilon = unit_roundoff::<K>();
+        if epsilon < 1. {
+            return self.numerical_rank(epsilon);
+        }
         let m = self.clone().reduced_row_echelon();
         m.values
             .iter()
             .filter(|row| row.iter().any(|&x| x != K::zero()))
             .count()
     }
+
+    // Rounding leaves tiny non-zero residues where exact elimination would
+    // produce zeros, so for floating point types pivots below
+    // max(rows, cols) * eps * max |a_ij| count as zero. Uses partial pivoting
+    // to keep the growth of the residues in check.
+    fn numerical_rank(&self, epsilon: f64) -> usize {
+        let mut m = self.values.clone();
+        let rows = m.len();
+        let cols = m.first().map_or(0, |row| row.len());
+        let largest = m
+            .iter()
+            .flatten()
+            .fold(0., |acc: f64, x| acc.max(x.magnitude()));
+        let tolerance = rows.max(cols) as f64 * epsilon * largest;
+        let mut rank = 0;
+        for j in 0..cols {
+            if rank == rows {
+                break;
+            }
+            let pivot = (rank..rows)
+                .max_by(|&a, &b| m[a][j].magnitude().total_cmp(&m[b][j].magnitude()))
+                .unwrap();
+            if m[pivot][j].magnitude() <= tolerance {
+                continue;
+            }
+            m.swap(rank, pivot);
+            let (top, bottom) = m.split_at_mut(rank + 1);
+            let pivot_row = &top[rank];
+            for row in bottom.iter_mut() {
+                let factor = row[j] / pivot_row[j];
+                for (x, &p) in row[j..].iter_mut().zip(&pivot_row[j..]) {
+                    *x = *x - factor * p;
+                }
+            }
+            rank += 1;
+        }
+        rank
+    }
 }
 
 #[cfg(test)]
@@ -48,4 +90,12 @@ mod tests {
 
         assert_eq!(m.rank(), 3);
     }
+
+    #[test]
+    fn rank_ignores_rounding_residues() {
+        let m = Matrix::from(&[&[0.1, 0.2, 0.3], &[0.4, 0.5, 0.6], &[0.7, 0.8, 0.9]]);
+        assert_eq!(m.rank(), 2);
+        let tiny = Matrix::from(&[&[1e-300, 0.], &[0., 2e-300]]);
+        assert_eq!(tiny.rank(), 2);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{matrix::Matrix, numeric::Numeric};
+use crate::{condition::unit_roundoff, matrix::Matrix, numeric::Numeric};
 
 impl<K: Numeric> Matrix<K> {
     pub fn divide_row(&mut self, row: usize, divisor: K) {
@@ -22,30 +22,42 @@ impl<K: Numeric> Matrix<K> {
         }
     }
 
+    // Integer division truncates, so integer types eliminate fraction-free
+    // (Bareiss): every entry stays an exact minor of the original matrix.
     pub fn row_echelon(mut self) -> Self {
         let rows = self.values.len();
         let cols = if rows > 0 { self.values[0].len() } else { 0 };
+        let fraction_free = unit_roundoff::<K>() >= 1.;
         let mut pivot_row = 0;
+        let mut previous = K::one();
 
-        for i in 0..rows {
-            let mut j = 0;
-            while self.values[pivot_row][j] == K::zero() {
-                j += 1;
-                if j == cols {
-                    break;
-                }
+        for j in 0..cols {
+            if pivot_row == rows {
+                break;
             }
-            if j == cols {
-                pivot_row += 1;
+            let Some(i) = (pivot_row..rows).find(|&i| self.values[i][j] != K::zero()) else {
                 continue;
+            };
+            self.values.swap(pivot_row, i);
+            let pivot = self.values[pivot_row][j];
+            for k in pivot_row + 1..rows {
+                if fraction_free {
+                    let (top, bottom) = self.values.split_at_mut(k);
+                    let source = &top[pivot_row];
+                    let target = &mut bottom[0];
+                    let factor = target[j];
+                    for l in j..cols {
+                        target[l] = (target[l] * pivot - factor * source[l]) / previous;
+                    }
+                    continue;
+                }
+                let factor = self.values[k][j] / pivot;
+                if factor != K::zero() {
+                    self.subtract_multiple_of_row(pivot_row, k, factor);
+                    self.values[k][j] = K::zero();
+                }
             }
-            if pivot_row != i {
-                self.values.swap(pivot_row, i);
-            }
-            for k in i + 1..rows {
-                let leading_value = self.values[k][j];
-                self.subtract_multiple_of_row(i, k, leading_value);
-            }
+            previous = pivot;
             pivot_row += 1;
         }
         self
@@ -147,4 +159,21 @@ mod tests {
         ]);
         assert_eq!(m, expected);
     }
+
+    #[test]
+    fn row_echelon_moves_zero_rows_down_and_scales_eliminations() {
+        let m = Matrix::from(&[&[0., 0.], &[2., 4.], &[3., 1.]]).row_echelon();
+        let expected = Matrix::from(&[&[2., 4.], &[0., -5.], &[0., 0.]]);
+        assert_eq!(m, expected);
+    }
+
+    #[test]
+    fn integer_row_echelon_is_exact() {
+        let m = Matrix::from(&[&[2, 1], &[1, 1]]).row_echelon();
+        assert_eq!(m, Matrix::from(&[&[2, 1], &[0, 1]]));
+        let m = Matrix::from(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]]).row_echelon();
+        assert_eq!(m, Matrix::from(&[&[1, 2, 3], &[0, -3, -6], &[0, 0, 0]]));
+        let m = Matrix::from(&[&[0, 3, 1], &[2, 4, 5], &[4, 2, 1]]).row_echelon();
+        assert_eq!(m, Matrix::from(&[&[2, 4, 5], &[0, 6, 2], &[0, 0, -42]]));
+    }
 }
@@ -0,0 +1,255 @@
+use serde::{
+    de::Error,
+    ser::{SerializeSeq, SerializeStruct},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{lu::LuDecomposition, matrix::Matrix, numeric::Numeric, vector::Vector};
+
+// Vectors are plain sequences of their values.
+impl<K: Numeric + Serialize> Serialize for Vector<K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.values.serialize(serializer)
+    }
+}
+
+impl<'de, K: Numeric + Deserialize<'de>> Deserialize<'de> for Vector<K> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vector {
+            values: Vec::deserialize(deserializer)?,
+        })
+    }
+}
+
+// The entries of a matrix in row-major order, with an exact length so that
+// formats like bincode can write it without collecting first.
+struct Flat<'a, K>(&'a [Vec<K>], usize);
+
+impl<K: Serialize> Serialize for Flat<'_, K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.1))?;
+        for value in self.0.iter().flatten() {
+            seq.serialize_element(value)?;
+        }
+        seq.end()
+    }
+}
+
+// Matrices are stored as {rows, cols, data} with `data` flattened row by row.
+impl<K: Numeric + Serialize> Serialize for Matrix<K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let rows = self.values.len();
+        let cols = self.values.first().map_or(0, |row| row.len());
+        if self.values.iter().any(|row| row.len() != cols) {
+            return Err(serde::ser::Error::custom(
+                "Matrix rows must all have the same length",
+            ));
+        }
+        let mut state = serializer.serialize_struct("Matrix", 3)?;
+        state.serialize_field("rows", &rows)?;
+        state.serialize_field("cols", &cols)?;
+        state.serialize_field("data", &Flat(&self.values, rows * cols))?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Matrix", deny_unknown_fields)]
+struct MatrixData<K> {
+    rows: usize,
+    cols: usize,
+    data: Vec<K>,
+}
+
+impl<'de, K: Numeric + Deserialize<'de>> Deserialize<'de> for Matrix<K> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let MatrixData { rows, cols, data } = MatrixData::deserialize(deserializer)?;
+        if rows.checked_mul(cols) != Some(data.len()) {
+            return Err(D::Error::custom(format!(
+                "a {}x{} matrix needs {} entries, found {}",
+                rows,
+                cols,
+                rows.saturating_mul(cols),
+                data.len()
+            )));
+        }
+        // Without columns nothing in `data` backs the row count, so the rows
+        // are reserved fallibly.
+        let values = match cols {
+            0 => {
+                let mut values = Vec::new();
+                values
+                    .try_reserve_exact(rows)
+                    .map_err(|_| D::Error::custom(format!("a {}x0 matrix is too large", rows)))?;
+                values.resize(rows, Vec::new());
+                values
+            }
+            _ => data.chunks(cols).map(|row| row.to_vec()).collect(),
+        };
+        Ok(Matrix { values })
+    }
+}
+
+impl<K: Numeric + Serialize> Serialize for LuDecomposition<K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("LuDecomposition", 3)?;
+        state.serialize_field("lu", &self.lu)?;
+        state.serialize_field("permutation", &self.permutation)?;
+        state.serialize_field("swaps", &self.swaps)?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "LuDecomposition", deny_unknown_fields)]
+struct LuData<K: Numeric> {
+    lu: Matrix<K>,
+    permutation: Vec<usize>,
+    swaps: usize,
+}
+
+// Number of transpositions needed to sort `permutation`, or None if it is not
+// a permutation of 0..n.
+fn transpositions(permutation: &[usize]) -> Option<usize> {
+    let n = permutation.len();
+    let mut seen = vec![false; n];
+    let mut count = 0;
+    for start in 0..n {
+        if seen[start] {
+            continue;
+        }
+        let mut i = start;
+        let mut cycle = 0;
+        while !seen[i] {
+            seen[i] = true;
+            i = *permutation.get(i).filter(|&&next| next < n)?;
+            cycle += 1;
+        }
+        if i != start {
+            return None;
+        }
+        count += cycle - 1;
+    }
+    Some(count)
+}
+
+// Only the parity of `swaps` is checked, since it is what the determinant uses.
+impl<'de, K: Numeric + Deserialize<'de>> Deserialize<'de> for LuDecomposition<K> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let LuData {
+            lu,
+            permutation,
+            swaps,
+        } = LuData::deserialize(deserializer)?;
+        let n = permutation.len();
+        if lu.values.len() != n || lu.values.iter().any(|row| row.len() != n) {
+            return Err(D::Error::custom(format!(
+                "factors must be {}x{} to match the permutation",
+                n, n
+            )));
+        }
+        match transpositions(&permutation) {
+            None => Err(D::Error::custom("permutation is not a permutation of 0..n")),
+            Some(count) if count % 2 != swaps % 2 => Err(D::Error::custom(
+                "swap count does not match the parity of the permutation",
+            )),
+            Some(_) => Ok(LuDecomposition {
+                lu,
+                permutation,
+                swaps,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex_number::ComplexNumber;
+
+    #[test]
+    fn vectors_and_matrices_use_a_compact_form() {
+        let v = Vector::from(&[1., -2.5]);
+        assert_eq!(serde_json::to_string(&v).unwrap(), "[1.0,-2.5]");
+        let m = Matrix::from(&[&[1, 2, 3], &[4, 5, 6]]);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, r#"{"rows":2,"cols":3,"data":[1,2,3,4,5,6]}"#);
+        assert_eq!(serde_json::from_str::<Matrix<i32>>(&json).unwrap(), m);
+        assert_eq!(
+            serde_json::from_str::<Vector<f64>>("[1.0,-2.5]").unwrap(),
+            v
+        );
+    }
+
+    #[test]
+    fn deserialization_validates_the_shape() {
+        let error = serde_json::from_str::<Matrix<i32>>(r#"{"rows":2,"cols":2,"data":[1,2,3]}"#)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("a 2x2 matrix needs 4 entries, found 3"));
+        let empty: Matrix<i32> = serde_json::from_str(r#"{"rows":2,"cols":0,"data":[]}"#).unwrap();
+        assert_eq!(empty.values, vec![Vec::<i32>::new(), Vec::new()]);
+        let error = serde_json::from_str::<Matrix<i32>>(&format!(
+            r#"{{"rows":{},"cols":0,"data":[]}}"#,
+            usize::MAX
+        ))
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with(&format!("a {}x0 matrix is too large", usize::MAX)));
+    }
+
+    #[test]
+    fn complex_numbers_round_trip() {
+        let m = Matrix::from(&[&[ComplexNumber {
+            real: 1.,
+            imag: -1.,
+        }]]);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(
+            json,
+            r#"{"rows":1,"cols":1,"data":[{"real":1.0,"imag":-1.0}]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Matrix<ComplexNumber>>(&json).unwrap(),
+            m
+        );
+    }
+
+    #[test]
+    fn bincode_round_trip() {
+        let m = Matrix::from(&[&[1.5, 2.], &[3., 4.], &[5., 6.]]);
+        let bytes = bincode::serialize(&m).unwrap();
+        assert_eq!(bincode::deserialize::<Matrix<f64>>(&bytes).unwrap(), m);
+        let v = Vector::from(&[1i8, 2, 3]);
+        let bytes = bincode::serialize(&v).unwrap();
+        assert_eq!(bincode::deserialize::<Vector<i8>>(&bytes).unwrap(), v);
+    }
+
+    #[test]
+    fn lu_decompositions_round_trip_and_are_validated() {
+        let a = Matrix::from(&[&[1., 2., 0.], &[3., 4., 4.], &[5., 6., 3.]]);
+        let lu = a.lu().unwrap();
+        let json = serde_json::to_string(&lu).unwrap();
+        let restored: LuDecomposition<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, lu);
+        let bytes = bincode::serialize(&lu).unwrap();
+        assert_eq!(
+            bincode::deserialize::<LuDecomposition<f64>>(&bytes).unwrap(),
+            lu
+        );
+
+        let lu_json = r#"{"rows":2,"cols":2,"data":[1.0,0.0,0.0,1.0]}"#;
+        let parse = |permutation: &str, swaps: usize| {
+            serde_json::from_str::<LuDecomposition<f64>>(&format!(
+                r#"{{"lu":{},"permutation":{},"swaps":{}}}"#,
+                lu_json, permutation, swaps
+            ))
+        };
+        assert!(parse("[1,0]", 1).is_ok());
+        assert!(parse("[1,0]", 0).is_err());
+        assert!(parse("[1,1]", 0).is_err());
+        assert!(parse("[0,1,2]", 0).is_err());
+    }
+}
@@ -0,0 +1,362 @@
+// Specialised f32/f64 kernels behind the generic `Vector` operations.
+//
+// On x86_64 the reductions use AVX when the CPU supports it (detected at
+// runtime); everywhere else a scalar fallback runs. Both keep 4 registers of
+// LANES independent accumulators, feed element `start + r * LANES + l` of each
+// block into accumulator (r, l), combine the registers as (0 + 1) + (2 + 3),
+// fold the lanes as a pairwise tree and then add the leftover tail in order.
+// The fallback emulates exactly that order, so a given input produces the same
+// bits on every machine. They do differ from the sequential fold used for other
+// scalar types in the last few ulps, because the summation order is different.
+// Element-wise kernels (add, sub, scl) round each entry once either way and are
+// bit-identical to the generic path.
+
+use std::any::Any;
+
+use num::Float;
+
+const REGISTERS: usize = 4;
+
+// `Any` only works on sized types, which is why these take the `Vec` itself.
+#[allow(clippy::ptr_arg)]
+fn downcast<T: 'static, K: 'static>(values: &Vec<K>) -> Option<&Vec<T>> {
+    (values as &dyn Any).downcast_ref::<Vec<T>>()
+}
+
+#[allow(clippy::ptr_arg)]
+fn downcast_mut<T: 'static, K: 'static>(values: &mut Vec<K>) -> Option<&mut Vec<T>> {
+    (values as &mut dyn Any).downcast_mut::<Vec<T>>()
+}
+
+fn cast<T: 'static, K: 'static + Copy>(value: T) -> K {
+    *(&value as &dyn Any).downcast_ref::<K>().unwrap()
+}
+
+fn horizontal<T: Float>(lanes: &[T]) -> T {
+    if lanes.len() == 1 {
+        return lanes[0];
+    }
+    let (left, right) = lanes.split_at(lanes.len() / 2);
+    horizontal(left) + horizontal(right)
+}
+
+fn combine<T: Float, const LANES: usize>(acc: [[T; LANES]; REGISTERS]) -> T {
+    let mut lanes = [T::zero(); LANES];
+    for (l, lane) in lanes.iter_mut().enumerate() {
+        *lane = (acc[0][l] + acc[1][l]) + (acc[2][l] + acc[3][l]);
+    }
+    horizontal(&lanes)
+}
+
+fn scalar_blocks<T: Float, const LANES: usize>(
+    len: usize,
+    term: impl Fn(usize) -> T,
+) -> [[T; LANES]; REGISTERS] {
+    let block = REGISTERS * LANES;
+    let mut acc = [[T::zero(); LANES]; REGISTERS];
+    for start in (0..len / block * block).step_by(block) {
+        for (r, register) in acc.iter_mut().enumerate() {
+            for (l, lane) in register.iter_mut().enumerate() {
+                *lane = *lane + term(start + r * LANES + l);
+            }
+        }
+    }
+    acc
+}
+
+fn reduce<T: Float, const LANES: usize>(
+    len: usize,
+    blocks: Option<[[T; LANES]; REGISTERS]>,
+    term: impl Fn(usize) -> T,
+) -> T {
+    let blocks = blocks.unwrap_or_else(|| scalar_blocks(len, &term));
+    let full = len / (REGISTERS * LANES) * (REGISTERS * LANES);
+    (full..len).fold(combine(blocks), |acc, i| acc + term(i))
+}
+
+#[cfg(target_arch = "x86_64")]
+fn has_avx() -> bool {
+    is_x86_feature_detected!("avx")
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn has_avx() -> bool {
+    false
+}
+
+// The AVX kernels read `b` up to `a.len()`, so both dot products check the
+// lengths first.
+pub fn dot_f64(a: &[f64], b: &[f64]) -> f64 {
+    if a.len() != b.len() {
+        panic!("Vectors must be the same length");
+    }
+    #[cfg(target_arch = "x86_64")]
+    let blocks = has_avx().then(|| unsafe { avx::dot_f64(a, b) });
+    #[cfg(not(target_arch = "x86_64"))]
+    let blocks = None;
+    reduce::<f64, 4>(a.len(), blocks, |i| a[i] * b[i])
+}
+
+pub fn dot_f32(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        panic!("Vectors must be the same length");
+    }
+    #[cfg(target_arch = "x86_64")]
+    let blocks = has_avx().then(|| unsafe { avx::dot_f32(a, b) });
+    #[cfg(not(target_arch = "x86_64"))]
+    let blocks = None;
+    reduce::<f32, 8>(a.len(), blocks, |i| a[i] * b[i])
+}
+
+pub fn sum_abs_f64(a: &[f64]) -> f64 {
+    #[cfg(target_arch = "x86_64")]
+    let blocks = has_avx().then(|| unsafe { avx::sum_abs_f64(a) });
+    #[cfg(not(target_arch = "x86_64"))]
+    let blocks = None;
+    reduce::<f64, 4>(a.len(), blocks, |i| a[i].abs())
+}
+
+// Widened to f64 like `Numeric::magnitude`, so f32 norms keep f64 precision.
+pub fn sum_abs_f32(a: &[f32]) -> f64 {
+    #[cfg(target_arch = "x86_64")]
+    let blocks = has_avx().then(|| unsafe { avx::sum_abs_f32(a) });
+    #[cfg(not(target_arch = "x86_64"))]
+    let blocks = None;
+    reduce::<f64, 4>(a.len(), blocks, |i| (a[i] as f64).abs())
+}
+
+// NaN entries are skipped, matching the generic `norm_inf`.
+pub fn max_abs_f64(a: &[f64]) -> f64 {
+    a.iter()
+        .fold(0., |acc, x| if x.abs() > acc { x.abs() } else { acc })
+}
+
+pub fn max_abs_f32(a: &[f32]) -> f64 {
+    a.iter().fold(0., |acc: f64, x| {
+        if x.abs() as f64 > acc {
+            x.abs() as f64
+        } else {
+            acc
+        }
+    })
+}
+
+fn zip_in_place<T: Copy>(a: &mut [T], b: &[T], f: impl Fn(T, T) -> T) {
+    a.iter_mut().zip(b).for_each(|(x, &y)| *x = f(*x, y));
+}
+
+pub fn dot<K: 'static + Copy>(a: &Vec<K>, b: &Vec<K>) -> Option<K> {
+    if let (Some(a), Some(b)) = (downcast::<f64, K>(a), downcast::<f64, K>(b)) {
+        return Some(cast(dot_f64(a, b)));
+    }
+    if let (Some(a), Some(b)) = (downcast::<f32, K>(a), downcast::<f32, K>(b)) {
+        return Some(cast(dot_f32(a, b)));
+    }
+    None
+}
+
+pub fn norm_1<K: 'static>(a: &Vec<K>) -> Option<f64> {
+    if let Some(a) = downcast::<f64, K>(a) {
+        return Some(sum_abs_f64(a));
+    }
+    downcast::<f32, K>(a).map(|a| sum_abs_f32(a))
+}
+
+pub fn norm_2<K: 'static + Copy>(a: &Vec<K>) -> Option<K> {
+    if let Some(a) = downcast::<f64, K>(a) {
+        return Some(cast(dot_f64(a, a).sqrt()));
+    }
+    downcast::<f32, K>(a).map(|a| cast(dot_f32(a, a).sqrt()))
+}
+
+pub fn norm_inf<K: 'static>(a: &Vec<K>) -> Option<f64> {
+    if let Some(a) = downcast::<f64, K>(a) {
+        return Some(max_abs_f64(a));
+    }
+    downcast::<f32, K>(a).map(|a| max_abs_f32(a))
+}
+
+pub fn add<K: 'static>(a: &mut Vec<K>, b: &Vec<K>) -> bool {
+    elementwise(a, b, |x, y| x + y, |x, y| x + y)
+}
+
+pub fn sub<K: 'static>(a: &mut Vec<K>, b: &Vec<K>) -> bool {
+    elementwise(a, b, |x, y| x - y, |x, y| x - y)
+}
+
+pub fn scl<K: 'static + Copy>(a: &mut Vec<K>, factor: K) -> bool {
+    if let Some(a) = downcast_mut::<f64, K>(a) {
+        let factor: f64 = cast(factor);
+        a.iter_mut().for_each(|x| *x *= factor);
+        return true;
+    }
+    if let Some(a) = downcast_mut::<f32, K>(a) {
+        let factor: f32 = cast(factor);
+        a.iter_mut().for_each(|x| *x *= factor);
+        return true;
+    }
+    false
+}
+
+// Plain slice loops over f32/f64 are auto-vectorised by LLVM, which is all the
+// element-wise operations need.
+fn elementwise<K: 'static>(
+    a: &mut Vec<K>,
+    b: &Vec<K>,
+    f64_op: impl Fn(f64, f64) -> f64,
+    f32_op: impl Fn(f32, f32) -> f32,
+) -> bool {
+    if let Some(b) = downcast::<f64, K>(b) {
+        zip_in_place(downcast_mut::<f64, K>(a).unwrap(), b, f64_op);
+        return true;
+    }
+    if let Some(b) = downcast::<f32, K>(b) {
+        zip_in_place(downcast_mut::<f32, K>(a).unwrap(), b, f32_op);
+        return true;
+    }
+    false
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx {
+    use std::arch::x86_64::*;
+
+    use super::REGISTERS;
+
+    // Each function only walks the full blocks of REGISTERS * LANES elements;
+    // the caller adds the tail.
+
+    #[target_feature(enable = "avx")]
+    pub unsafe fn dot_f64(a: &[f64], b: &[f64]) -> [[f64; 4]; REGISTERS] {
+        let mut acc = [_mm256_setzero_pd(); REGISTERS];
+        for start in (0..a.len() / 16 * 16).step_by(16) {
+            for (r, register) in acc.iter_mut().enumerate() {
+                let x = unsafe { _mm256_loadu_pd(a.as_ptr().add(start + r * 4)) };
+                let y = unsafe { _mm256_loadu_pd(b.as_ptr().add(start + r * 4)) };
+                *register = _mm256_add_pd(*register, _mm256_mul_pd(x, y));
+            }
+        }
+        acc.map(|register| unsafe { std::mem::transmute::<__m256d, [f64; 4]>(register) })
+    }
+
+    #[target_feature(enable = "avx")]
+    pub unsafe fn dot_f32(a: &[f32], b: &[f32]) -> [[f32; 8]; REGISTERS] {
+        let mut acc = [_mm256_setzero_ps(); REGISTERS];
+        for start in (0..a.len() / 32 * 32).step_by(32) {
+            for (r, register) in acc.iter_mut().enumerate() {
+                let x = unsafe { _mm256_loadu_ps(a.as_ptr().add(start + r * 8)) };
+                let y = unsafe { _mm256_loadu_ps(b.as_ptr().add(start + r * 8)) };
+                *register = _mm256_add_ps(*register, _mm256_mul_ps(x, y));
+            }
+        }
+        acc.map(|register| unsafe { std::mem::transmute::<__m256, [f32; 8]>(register) })
+    }
+
+    #[target_feature(enable = "avx")]
+    pub unsafe fn sum_abs_f64(a: &[f64]) -> [[f64; 4]; REGISTERS] {
+        let sign = _mm256_set1_pd(-0.0);
+        let mut acc = [_mm256_setzero_pd(); REGISTERS];
+        for start in (0..a.len() / 16 * 16).step_by(16) {
+            for (r, register) in acc.iter_mut().enumerate() {
+                let x = unsafe { _mm256_loadu_pd(a.as_ptr().add(start + r * 4)) };
+                *register = _mm256_add_pd(*register, _mm256_andnot_pd(sign, x));
+            }
+        }
+        acc.map(|register| unsafe { std::mem::transmute::<__m256d, [f64; 4]>(register) })
+    }
+
+    #[target_feature(enable = "avx")]
+    pub unsafe fn sum_abs_f32(a: &[f32]) -> [[f64; 4]; REGISTERS] {
+        let sign = _mm256_set1_pd(-0.0);
+        let mut acc = [_mm256_setzero_pd(); REGISTERS];
+        for start in (0..a.len() / 16 * 16).step_by(16) {
+            for (r, register) in acc.iter_mut().enumerate() {
+                let x = _mm256_cvtps_pd(unsafe { _mm_loadu_ps(a.as_ptr().add(start + r * 4)) });
+                *register = _mm256_add_pd(*register, _mm256_andnot_pd(sign, x));
+            }
+        }
+        acc.map(|register| unsafe { std::mem::transmute::<__m256d, [f64; 4]>(register) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn sequential_dot(a: &[f64], b: &[f64]) -> f64 {
+        a.iter().zip(b).fold(0., |acc, (x, y)| acc + x * y)
+    }
+
+    #[test]
+    fn scalar_fallback_matches_vector_path_bit_for_bit() {
+        let a: Vec<f64> = (0..103).map(|i| (i as f64 * 0.37).sin()).collect();
+        let b: Vec<f64> = (0..103).map(|i| (i as f64 * 1.3).cos()).collect();
+        let fallback = reduce::<f64, 4>(a.len(), None, |i| a[i] * b[i]);
+        assert_eq!(dot_f64(&a, &b).to_bits(), fallback.to_bits());
+
+        let a32: Vec<f32> = a.iter().map(|&x| x as f32).collect();
+        let fallback = reduce::<f32, 8>(a32.len(), None, |i| a32[i] * a32[i]);
+        assert_eq!(dot_f32(&a32, &a32).to_bits(), fallback.to_bits());
+        let fallback = reduce::<f64, 4>(a32.len(), None, |i| (a32[i] as f64).abs());
+        assert_eq!(sum_abs_f32(&a32).to_bits(), fallback.to_bits());
+    }
+
+    #[test]
+    fn short_inputs_only_use_the_tail() {
+        assert_eq!(dot_f64(&[1., 2., 3.], &[4., 5., 6.]), 32.);
+        assert_eq!(dot_f32(&[], &[]), 0.);
+        assert_eq!(sum_abs_f64(&[-1., 2.]), 3.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dot_f64_panics_on_shorter_rhs() {
+        dot_f64(&[1.; 20], &[1.; 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dot_f32_panics_on_shorter_rhs() {
+        dot_f32(&[1.; 40], &[1.; 3]);
+    }
+
+    #[test]
+    fn dispatch_only_specialises_floats() {
+        assert_eq!(dot(&vec![1., 2.], &vec![3., 4.]), Some(11.));
+        assert_eq!(dot(&vec![1, 2], &vec![3, 4]), None);
+        assert_eq!(norm_1(&vec![-1f32, 2.]), Some(3.));
+        assert!(!add(&mut vec![1, 2], &vec![3, 4]));
+    }
+
+    proptest! {
+        #[test]
+        fn dot_agrees_with_sequential_fold(
+            values in prop::collection::vec((-1e3f64..1e3, -1e3f64..1e3), 0..200)
+        ) {
+            let (a, b): (Vec<f64>, Vec<f64>) = values.into_iter().unzip();
+            let bound = a.iter().zip(&b).map(|(x, y)| (x * y).abs()).sum::<f64>();
+            let error = (dot_f64(&a, &b) - sequential_dot(&a, &b)).abs();
+            prop_assert!(error <= 2. * a.len() as f64 * f64::EPSILON * bound);
+        }
+
+        #[test]
+        fn f32_dot_agrees_with_sequential_fold(
+            values in prop::collection::vec((-1e2f32..1e2, -1e2f32..1e2), 0..200)
+        ) {
+            let (a, b): (Vec<f32>, Vec<f32>) = values.into_iter().unzip();
+            let expected = a.iter().zip(&b).fold(0f32, |acc, (x, y)| acc + x * y);
+            let bound = a.iter().zip(&b).map(|(x, y)| (x * y).abs()).sum::<f32>();
+            let error = (dot_f32(&a, &b) - expected).abs();
+            prop_assert!(error <= 2. * a.len() as f32 * f32::EPSILON * bound);
+        }
+
+        #[test]
+        fn norms_agree_with_sequential_fold(a in prop::collection::vec(-1e3f64..1e3, 0..200)) {
+            let sum_abs = a.iter().fold(0., |acc, x| acc + x.abs());
+            prop_assert!((sum_abs_f64(&a) - sum_abs).abs() <= a.len() as f64 * f64::EPSILON * sum_abs);
+            let max = a.iter().fold(0f64, |acc, x| acc.max(x.abs()));
+            prop_assert_eq!(max_abs_f64(&a), max);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use num::Float;
+
+use crate::{linear_operator::LinearOperator, numeric::Numeric, vector::Vector};
+
+use super::{
+    axpy, precondition, residual, ConvergenceStatus, IterativeResult, Preconditioner,
+    SolverOptions, State,
+};
+
+pub fn bicgstab<K, A>(
+    a: &A,
+    b: &Vector<K>,
+    preconditioner: Option<&dyn Preconditioner<K>>,
+    options: &SolverOptions<K>,
+) -> IterativeResult<K>
+where
+    K: Numeric + Float,
+    A: LinearOperator<K> + ?Sized,
+{
+    let mut state = State::new(a, b, options);
+    let mut r = residual(a, b, &state.x);
+    if state.record(r.norm_2()) {
+        return state.finish(ConvergenceStatus::Converged);
+    }
+    let r_hat = r.clone();
+    let (mut rho, mut alpha, mut omega) = (K::one(), K::one(), K::one());
+    let mut v = Vector {
+        values: vec![K::zero(); r.values.len()],
+    };
+    let mut p = v.clone();
+
+    for _ in 0..options.max_iterations {
+        let rho_next = r_hat.dot(&r);
+        if rho_next == K::zero() || omega == K::zero() {
+            return state.finish(ConvergenceStatus::Breakdown);
+        }
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        axpy(&mut p, -omega, &v);
+        p.scl(beta);
+        p.add(&r);
+
+        let p_hat = precondition(preconditioner, &p);
+        v = a.apply(&p_hat);
+        let r_hat_v = r_hat.dot(&v);
+        if r_hat_v == K::zero() {
+            return state.finish(ConvergenceStatus::Breakdown);
+        }
+        alpha = rho / r_hat_v;
+        axpy(&mut r, -alpha, &v);
+        axpy(&mut state.x, alpha, &p_hat);
+        let s_norm = r.norm_2();
+        if s_norm <= state.threshold {
+            state.record(s_norm);
+            return state.finish(ConvergenceStatus::Converged);
+        }
+
+        let s_hat = precondition(preconditioner, &r);
+        let t = a.apply(&s_hat);
+        let tt = t.dot(&t);
+        omega = if tt == K::zero() {
+            K::zero()
+        } else {
+            t.dot(&r) / tt
+        };
+        axpy(&mut state.x, omega, &s_hat);
+        axpy(&mut r, -omega, &t);
+        if state.record(r.norm_2()) {
+            return state.finish(ConvergenceStatus::Converged);
+        }
+    }
+    state.finish(ConvergenceStatus::MaxIterationsReached)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::iterative::{tests::nonsymmetric, Ilu0Preconditioner};
+    use crate::sparse::CsrMatrix;
+
+    #[test]
+    fn bicgstab_solves_nonsymmetric_system() {
+        let a = nonsymmetric();
+        let b = Vector::from(&[1., 2., 3., 4.]);
+
+        let result = bicgstab(&a, &b, None, &SolverOptions::default());
+
+        assert!(result.converged());
+        let mut r = b.clone();
+        r.sub(&a.mul_vec(result.solution));
+        assert!(r.norm_2() < 1e-8);
+    }
+
+    #[test]
+    fn bicgstab_with_ilu0_converges_faster() {
+        let a = CsrMatrix::from_dense(&nonsymmetric());
+        let b = Vector::from(&[1., -1., 1., -1.]);
+        let m = Ilu0Preconditioner::new(&a).unwrap();
+
+        let plain = bicgstab(&a, &b, None, &SolverOptions::default());
+        let preconditioned = bicgstab(&a, &b, Some(&m), &SolverOptions::default());
+
+        assert!(preconditioned.converged());
+        assert!(preconditioned.iterations <= plain.iterations);
+    }
+}
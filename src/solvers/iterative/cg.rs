@@ -0,0 +1,110 @@
+use num::Float;
+
+use crate::{linear_operator::LinearOperator, numeric::Numeric, vector::Vector};
+
+use super::{
+    axpy, precondition, residual, ConvergenceStatus, IterativeResult, Preconditioner,
+    SolverOptions, State,
+};
+
+pub fn conjugate_gradient<K, A>(
+    a: &A,
+    b: &Vector<K>,
+    preconditioner: Option<&dyn Preconditioner<K>>,
+    options: &SolverOptions<K>,
+) -> IterativeResult<K>
+where
+    K: Numeric + Float,
+    A: LinearOperator<K> + ?Sized,
+{
+    let mut state = State::new(a, b, options);
+    let mut r = residual(a, b, &state.x);
+    if state.record(r.norm_2()) {
+        return state.finish(ConvergenceStatus::Converged);
+    }
+    let mut z = precondition(preconditioner, &r);
+    let mut p = z.clone();
+    let mut rz = r.dot(&z);
+
+    for _ in 0..options.max_iterations {
+        let ap = a.apply(&p);
+        let pap = p.dot(&ap);
+        if pap == K::zero() {
+            return state.finish(ConvergenceStatus::Breakdown);
+        }
+        let alpha = rz / pap;
+        axpy(&mut state.x, alpha, &p);
+        axpy(&mut r, -alpha, &ap);
+        if state.record(r.norm_2()) {
+            return state.finish(ConvergenceStatus::Converged);
+        }
+        z = precondition(preconditioner, &r);
+        let rz_next = r.dot(&z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        p.scl(beta);
+        p.add(&z);
+    }
+    state.finish(ConvergenceStatus::MaxIterationsReached)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::iterative::{tests::poisson, JacobiPreconditioner};
+
+    #[test]
+    fn conjugate_gradient_solves_spd_system() {
+        let a = poisson(20);
+        let expected = Vector {
+            values: (0..20).map(|i| i as f64).collect(),
+        };
+        let b = a.mul_vec(&expected);
+
+        let result = conjugate_gradient(&a, &b, None, &SolverOptions::default());
+
+        assert!(result.converged());
+        assert!(result.iterations <= 20);
+        assert_eq!(result.residual_history.len(), result.iterations + 1);
+        let mut error = result.solution.clone();
+        error.sub(&expected);
+        assert!(error.norm_2() < 1e-8);
+    }
+
+    #[test]
+    fn conjugate_gradient_accepts_a_preconditioner() {
+        let a = poisson(10);
+        let b = Vector::from(&[1.; 10]);
+        let m = JacobiPreconditioner::from_csr(&a).unwrap();
+
+        let result = conjugate_gradient(&a, &b, Some(&m), &SolverOptions::default());
+
+        assert!(result.converged());
+        let mut r = b.clone();
+        r.sub(&a.mul_vec(&result.solution));
+        assert!(r.norm_2() < 1e-8);
+    }
+
+    #[test]
+    fn conjugate_gradient_reports_max_iterations() {
+        let a = poisson(50);
+        let b = Vector::from(&[1.; 50]);
+
+        let result = conjugate_gradient(&a, &b, None, &SolverOptions::new(1e-12, 3));
+
+        assert_eq!(result.status, ConvergenceStatus::MaxIterationsReached);
+        assert_eq!(result.iterations, 3);
+    }
+
+    #[test]
+    fn zero_right_hand_side_converges_immediately() {
+        let a = poisson(4);
+        let b = Vector::from(&[0.; 4]);
+
+        let result = conjugate_gradient(&a, &b, None, &SolverOptions::default());
+
+        assert!(result.converged());
+        assert_eq!(result.iterations, 0);
+        assert_eq!(result.solution, b);
+    }
+}
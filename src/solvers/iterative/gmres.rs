@@ -0,0 +1,171 @@
+use num::Float;
+
+use crate::{linear_operator::LinearOperator, numeric::Numeric, vector::Vector};
+
+use super::{
+    axpy, precondition, residual, ConvergenceStatus, IterativeResult, Preconditioner,
+    SolverOptions, State,
+};
+
+// Restarted GMRES with right preconditioning, so the residual tracked by the
+// Givens rotations is the true residual of the original system.
+pub fn gmres<K, A>(
+    a: &A,
+    b: &Vector<K>,
+    restart: usize,
+    preconditioner: Option<&dyn Preconditioner<K>>,
+    options: &SolverOptions<K>,
+) -> IterativeResult<K>
+where
+    K: Numeric + Float,
+    A: LinearOperator<K> + ?Sized,
+{
+    if restart == 0 {
+        panic!("GMRES restart length must be at least 1");
+    }
+    let mut state = State::new(a, b, options);
+    let mut r = residual(a, b, &state.x);
+    let mut beta = r.norm_2();
+    if state.record(beta) {
+        return state.finish(ConvergenceStatus::Converged);
+    }
+    let mut iterations = 0;
+
+    while iterations < options.max_iterations {
+        r.scl(K::one() / beta);
+        let mut basis = vec![r.clone()];
+        let mut h: Vec<Vec<K>> = Vec::new();
+        let mut rotations: Vec<(K, K)> = Vec::new();
+        let mut g = vec![beta];
+        let mut converged = false;
+
+        while basis.len() <= restart && iterations < options.max_iterations {
+            let j = basis.len() - 1;
+            let mut w = a.apply(&precondition(preconditioner, &basis[j]));
+            let mut column = Vec::with_capacity(j + 2);
+            for v in &basis {
+                let hij = w.dot(v);
+                axpy(&mut w, -hij, v);
+                column.push(hij);
+            }
+            let norm = w.norm_2();
+            column.push(norm);
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (x, y) = (column[i], column[i + 1]);
+                column[i] = c * x + s * y;
+                column[i + 1] = c * y - s * x;
+            }
+            let denominator = column[j].hypot(column[j + 1]);
+            let (c, s) = if denominator == K::zero() {
+                (K::one(), K::zero())
+            } else {
+                (column[j] / denominator, column[j + 1] / denominator)
+            };
+            column[j] = denominator;
+            column[j + 1] = K::zero();
+            rotations.push((c, s));
+            g.push(-s * g[j]);
+            g[j] = c * g[j];
+            h.push(column);
+            iterations += 1;
+
+            converged = state.record(g[j + 1].abs());
+            if converged || norm == K::zero() {
+                break;
+            }
+            w.scl(K::one() / norm);
+            basis.push(w);
+        }
+
+        let k = h.len();
+        if h.iter()
+            .enumerate()
+            .any(|(i, column)| column[i] == K::zero())
+        {
+            return state.finish(ConvergenceStatus::Breakdown);
+        }
+        let mut y = vec![K::zero(); k];
+        for i in (0..k).rev() {
+            let sum = (i + 1..k).fold(g[i], |acc, l| acc - h[l][i] * y[l]);
+            y[i] = sum / h[i][i];
+        }
+        let mut update = Vector {
+            values: vec![K::zero(); b.values.len()],
+        };
+        for (v, &coefficient) in basis.iter().zip(y.iter()) {
+            axpy(&mut update, coefficient, v);
+        }
+        state.x.add(&precondition(preconditioner, &update));
+
+        if converged {
+            return state.finish(ConvergenceStatus::Converged);
+        }
+        r = residual(a, b, &state.x);
+        beta = r.norm_2();
+        if beta <= state.threshold {
+            return state.finish(ConvergenceStatus::Converged);
+        }
+    }
+    state.finish(ConvergenceStatus::MaxIterationsReached)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::iterative::{tests::nonsymmetric, tests::poisson, JacobiPreconditioner};
+
+    #[test]
+    fn full_gmres_solves_in_at_most_n_steps() {
+        let a = nonsymmetric();
+        let b = Vector::from(&[1., 2., 3., 4.]);
+
+        let result = gmres(&a, &b, 4, None, &SolverOptions::default());
+
+        assert!(result.converged());
+        assert!(result.iterations <= 4);
+        let mut r = b.clone();
+        r.sub(&a.mul_vec(result.solution));
+        assert!(r.norm_2() < 1e-8);
+    }
+
+    #[test]
+    fn restarted_gmres_converges() {
+        let a = poisson(30);
+        let b = Vector::from(&[1.; 30]);
+        let m = JacobiPreconditioner::from_csr(&a).unwrap();
+
+        let result = gmres(&a, &b, 10, Some(&m), &SolverOptions::new(1e-10, 2000));
+
+        assert!(result.converged());
+        let mut r = b.clone();
+        r.sub(&a.mul_vec(&result.solution));
+        assert!(r.norm_2() < 1e-8);
+    }
+
+    #[test]
+    fn residual_history_is_non_increasing() {
+        let a = nonsymmetric();
+        let b = Vector::from(&[1., 0., 0., 1.]);
+
+        let result = gmres(&a, &b, 4, None, &SolverOptions::default());
+
+        assert!(result
+            .residual_history
+            .windows(2)
+            .all(|w| w[1] <= w[0] + 1e-12));
+    }
+
+    #[test]
+    #[should_panic]
+    fn gmres_panics_on_zero_restart() {
+        let a = nonsymmetric();
+        gmres(
+            &a,
+            &Vector::from(&[1.; 4]),
+            0,
+            None,
+            &SolverOptions::default(),
+        );
+    }
+}
@@ -0,0 +1,169 @@
+mod bicgstab;
+mod cg;
+mod gmres;
+mod preconditioner;
+mod stationary;
+
+pub use bicgstab::bicgstab;
+pub use cg::conjugate_gradient;
+pub use gmres::gmres;
+pub use preconditioner::{Ilu0Preconditioner, JacobiPreconditioner, Preconditioner};
+pub use stationary::{gauss_seidel, jacobi};
+
+use num::Float;
+
+use crate::{linear_operator::LinearOperator, numeric::Numeric, vector::Vector};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolverOptions<K: Numeric> {
+    pub tolerance: K,
+    pub max_iterations: usize,
+    pub initial_guess: Option<Vector<K>>,
+}
+
+impl<K: Numeric + Float> SolverOptions<K> {
+    pub fn new(tolerance: K, max_iterations: usize) -> Self {
+        SolverOptions {
+            tolerance,
+            max_iterations,
+            initial_guess: None,
+        }
+    }
+}
+
+impl<K: Numeric + Float> Default for SolverOptions<K> {
+    fn default() -> Self {
+        SolverOptions::new(K::from(1e-10).unwrap(), 1000)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConvergenceStatus {
+    Converged,
+    MaxIterationsReached,
+    Breakdown,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IterativeResult<K: Numeric> {
+    pub solution: Vector<K>,
+    pub iterations: usize,
+    pub residual_history: Vec<K>,
+    pub status: ConvergenceStatus,
+}
+
+impl<K: Numeric> IterativeResult<K> {
+    pub fn converged(&self) -> bool {
+        self.status == ConvergenceStatus::Converged
+    }
+}
+
+// Shared bookkeeping for every solver: the residual history, the absolute
+// stopping threshold (tolerance relative to the norm of b) and the iterate.
+struct State<K: Numeric + Float> {
+    x: Vector<K>,
+    history: Vec<K>,
+    threshold: K,
+}
+
+impl<K: Numeric + Float> State<K> {
+    fn new<A: LinearOperator<K> + ?Sized>(
+        a: &A,
+        b: &Vector<K>,
+        options: &SolverOptions<K>,
+    ) -> Self {
+        let (rows, cols) = a.shape();
+        if rows != cols {
+            panic!("Iterative solvers need a square operator");
+        }
+        if rows != b.values.len() {
+            panic!("Operator and right-hand side dimensions don't match");
+        }
+        let x = match &options.initial_guess {
+            Some(guess) if guess.values.len() != cols => {
+                panic!("Initial guess has the wrong dimension")
+            }
+            Some(guess) => guess.clone(),
+            None => Vector {
+                values: vec![K::zero(); cols],
+            },
+        };
+        State {
+            x,
+            history: Vec::new(),
+            threshold: options.tolerance * b.norm_2(),
+        }
+    }
+
+    fn record(&mut self, residual: K) -> bool {
+        self.history.push(residual);
+        residual <= self.threshold
+    }
+
+    fn finish(self, status: ConvergenceStatus) -> IterativeResult<K> {
+        IterativeResult {
+            solution: self.x,
+            iterations: self.history.len().saturating_sub(1),
+            residual_history: self.history,
+            status,
+        }
+    }
+}
+
+fn residual<K: Numeric, A: LinearOperator<K> + ?Sized>(
+    a: &A,
+    b: &Vector<K>,
+    x: &Vector<K>,
+) -> Vector<K> {
+    let mut r = b.clone();
+    r.sub(&a.apply(x));
+    r
+}
+
+fn axpy<K: Numeric>(y: &mut Vector<K>, a: K, x: &Vector<K>) {
+    y.values
+        .iter_mut()
+        .zip(x.values.iter())
+        .for_each(|(y, &x)| *y += a * x);
+}
+
+fn precondition<K: Numeric>(
+    preconditioner: Option<&dyn Preconditioner<K>>,
+    r: &Vector<K>,
+) -> Vector<K> {
+    match preconditioner {
+        Some(m) => m.apply(r),
+        None => r.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{matrix::Matrix, sparse::CsrMatrix};
+
+    // 1D Poisson matrix: symmetric positive definite and diagonally dominant.
+    pub fn poisson(n: usize) -> CsrMatrix<f64> {
+        let mut m = Matrix {
+            values: vec![vec![0.; n]; n],
+        };
+        for i in 0..n {
+            m.values[i][i] = 2.;
+            if i > 0 {
+                m.values[i][i - 1] = -1.;
+            }
+            if i + 1 < n {
+                m.values[i][i + 1] = -1.;
+            }
+        }
+        CsrMatrix::from_dense(&m)
+    }
+
+    pub fn nonsymmetric() -> Matrix<f64> {
+        Matrix::from(&[
+            &[4., 1., 0., 0.],
+            &[-2., 5., 1., 0.],
+            &[0., -1., 6., 2.],
+            &[1., 0., -2., 7.],
+        ])
+    }
+}
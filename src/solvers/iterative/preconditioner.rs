@@ -0,0 +1,176 @@
+use num::Float;
+
+use crate::{matrix::Matrix, numeric::Numeric, sparse::CsrMatrix, vector::Vector};
+
+pub trait Preconditioner<K: Numeric> {
+    fn apply(&self, r: &Vector<K>) -> Vector<K>;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JacobiPreconditioner<K: Numeric> {
+    inverse_diagonal: Vec<K>,
+}
+
+impl<K: Numeric + Float> JacobiPreconditioner<K> {
+    pub fn new(diagonal: &[K]) -> Result<Self, String> {
+        if diagonal.iter().any(|&d| d == K::zero()) {
+            return Err("Jacobi preconditioner needs a non-zero diagonal".to_string());
+        }
+        Ok(JacobiPreconditioner {
+            inverse_diagonal: diagonal.iter().map(|&d| K::one() / d).collect(),
+        })
+    }
+
+    pub fn from_csr(a: &CsrMatrix<K>) -> Result<Self, String> {
+        let n = a.rows().min(a.cols());
+        let diagonal: Vec<K> = (0..n).map(|i| a.get(i, i)).collect();
+        Self::new(&diagonal)
+    }
+
+    pub fn from_dense(a: &Matrix<K>) -> Result<Self, String> {
+        let diagonal: Vec<K> = a
+            .values
+            .iter()
+            .enumerate()
+            .filter_map(|(i, row)| row.get(i).copied())
+            .collect();
+        Self::new(&diagonal)
+    }
+}
+
+impl<K: Numeric> Preconditioner<K> for JacobiPreconditioner<K> {
+    fn apply(&self, r: &Vector<K>) -> Vector<K> {
+        if r.values.len() != self.inverse_diagonal.len() {
+            panic!("Preconditioner and vector dimensions don't match");
+        }
+        Vector {
+            values: r
+                .values
+                .iter()
+                .zip(self.inverse_diagonal.iter())
+                .map(|(&x, &d)| x * d)
+                .collect(),
+        }
+    }
+}
+
+// Incomplete LU factorization with the sparsity pattern of A. L (unit lower)
+// and U are stored together in one CSR matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ilu0Preconditioner<K: Numeric> {
+    factors: CsrMatrix<K>,
+    diagonal_positions: Vec<usize>,
+}
+
+impl<K: Numeric + Float> Ilu0Preconditioner<K> {
+    pub fn new(a: &CsrMatrix<K>) -> Result<Self, String> {
+        if a.rows() != a.cols() {
+            return Err("ILU(0) needs a square matrix".to_string());
+        }
+        let n = a.rows();
+        let offsets = a.row_offsets();
+        let indices = a.col_indices();
+        let mut values = a.values().to_vec();
+        let mut diagonal_positions = vec![0; n];
+        for (i, position) in diagonal_positions.iter_mut().enumerate() {
+            *position = (offsets[i]..offsets[i + 1])
+                .find(|&k| indices[k] == i)
+                .ok_or_else(|| format!("ILU(0) needs a stored diagonal entry in row {}", i))?;
+        }
+
+        let mut position_in_row = vec![usize::MAX; n];
+        for i in 0..n {
+            for k in offsets[i]..offsets[i + 1] {
+                position_in_row[indices[k]] = k;
+            }
+            for k in offsets[i]..diagonal_positions[i] {
+                let pivot_row = indices[k];
+                let pivot = values[diagonal_positions[pivot_row]];
+                if pivot == K::zero() {
+                    return Err("ILU(0) encountered a zero pivot".to_string());
+                }
+                let factor = values[k] / pivot;
+                values[k] = factor;
+                for l in diagonal_positions[pivot_row] + 1..offsets[pivot_row + 1] {
+                    let target = position_in_row[indices[l]];
+                    if target != usize::MAX {
+                        values[target] = values[target] - factor * values[l];
+                    }
+                }
+            }
+            for k in offsets[i]..offsets[i + 1] {
+                position_in_row[indices[k]] = usize::MAX;
+            }
+            if values[diagonal_positions[i]] == K::zero() {
+                return Err("ILU(0) encountered a zero pivot".to_string());
+            }
+        }
+
+        Ok(Ilu0Preconditioner {
+            factors: CsrMatrix::from_raw_parts(n, n, offsets.to_vec(), indices.to_vec(), values),
+            diagonal_positions,
+        })
+    }
+}
+
+impl<K: Numeric> Preconditioner<K> for Ilu0Preconditioner<K> {
+    fn apply(&self, r: &Vector<K>) -> Vector<K> {
+        let n = self.diagonal_positions.len();
+        if r.values.len() != n {
+            panic!("Preconditioner and vector dimensions don't match");
+        }
+        let offsets = self.factors.row_offsets();
+        let indices = self.factors.col_indices();
+        let values = self.factors.values();
+        let mut y = r.values.clone();
+        for i in 0..n {
+            for k in offsets[i]..self.diagonal_positions[i] {
+                y[i] = y[i] - values[k] * y[indices[k]];
+            }
+        }
+        for i in (0..n).rev() {
+            for k in self.diagonal_positions[i] + 1..offsets[i + 1] {
+                y[i] = y[i] - values[k] * y[indices[k]];
+            }
+            y[i] = y[i] / values[self.diagonal_positions[i]];
+        }
+        Vector { values: y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jacobi_preconditioner_divides_by_diagonal() {
+        let a = Matrix::from(&[&[2., 1.], &[1., 4.]]);
+        let m = JacobiPreconditioner::from_dense(&a).unwrap();
+        assert_eq!(m.apply(&Vector::from(&[2., 2.])), Vector::from(&[1., 0.5]));
+    }
+
+    #[test]
+    fn jacobi_preconditioner_rejects_zero_diagonal() {
+        assert!(JacobiPreconditioner::new(&[1., 0.]).is_err());
+    }
+
+    #[test]
+    fn ilu0_is_exact_for_tridiagonal_matrices() {
+        let dense = Matrix::from(&[&[4., -1., 0.], &[-1., 4., -1.], &[0., -1., 4.]]);
+        let a = CsrMatrix::from_dense(&dense);
+        let m = Ilu0Preconditioner::new(&a).unwrap();
+        let x = Vector::from(&[1., 2., 3.]);
+
+        let y = m.apply(&a.mul_vec(&x));
+
+        let mut error = y.clone();
+        error.sub(&x);
+        assert!(error.norm_2() < 1e-12);
+    }
+
+    #[test]
+    fn ilu0_needs_stored_diagonal() {
+        let a = CsrMatrix::from_dense(&Matrix::from(&[&[0., 1.], &[1., 0.]]));
+        assert!(Ilu0Preconditioner::new(&a).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+use num::Float;
+
+use crate::{numeric::Numeric, sparse::CsrMatrix, vector::Vector};
+
+use super::{residual, ConvergenceStatus, IterativeResult, SolverOptions, State};
+
+// The splitting methods need the individual entries of A rather than just its
+// action, so they work on CSR storage; dense matrices go through
+// `CsrMatrix::from_dense`.
+fn diagonal<K: Numeric>(a: &CsrMatrix<K>) -> Result<Vec<K>, String> {
+    (0..a.rows())
+        .map(|i| match a.get(i, i) {
+            d if d == K::zero() => Err(format!(
+                "Splitting methods need a non-zero diagonal entry in row {}",
+                i
+            )),
+            d => Ok(d),
+        })
+        .collect()
+}
+
+pub fn jacobi<K: Numeric + Float>(
+    a: &CsrMatrix<K>,
+    b: &Vector<K>,
+    options: &SolverOptions<K>,
+) -> Result<IterativeResult<K>, String> {
+    let mut state = State::new(a, b, options);
+    let diagonal = diagonal(a)?;
+    let mut r = residual(a, b, &state.x);
+    if state.record(r.norm_2()) {
+        return Ok(state.finish(ConvergenceStatus::Converged));
+    }
+    for _ in 0..options.max_iterations {
+        state
+            .x
+            .values
+            .iter_mut()
+            .zip(r.values.iter().zip(diagonal.iter()))
+            .for_each(|(x, (&r, &d))| *x += r / d);
+        r = residual(a, b, &state.x);
+        if state.record(r.norm_2()) {
+            return Ok(state.finish(ConvergenceStatus::Converged));
+        }
+    }
+    Ok(state.finish(ConvergenceStatus::MaxIterationsReached))
+}
+
+pub fn gauss_seidel<K: Numeric + Float>(
+    a: &CsrMatrix<K>,
+    b: &Vector<K>,
+    options: &SolverOptions<K>,
+) -> Result<IterativeResult<K>, String> {
+    let mut state = State::new(a, b, options);
+    let diagonal = diagonal(a)?;
+    if state.record(residual(a, b, &state.x).norm_2()) {
+        return Ok(state.finish(ConvergenceStatus::Converged));
+    }
+    for _ in 0..options.max_iterations {
+        for (i, &d) in diagonal.iter().enumerate() {
+            let sum = a
+                .row(i)
+                .filter(|&(j, _)| j != i)
+                .fold(b.values[i], |acc, (j, value)| {
+                    acc - value * state.x.values[j]
+                });
+            state.x.values[i] = sum / d;
+        }
+        if state.record(residual(a, b, &state.x).norm_2()) {
+            return Ok(state.finish(ConvergenceStatus::Converged));
+        }
+    }
+    Ok(state.finish(ConvergenceStatus::MaxIterationsReached))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::iterative::tests::{nonsymmetric, poisson};
+
+    #[test]
+    fn jacobi_solves_diagonally_dominant_system() {
+        let a = CsrMatrix::from_dense(&nonsymmetric());
+        let b = Vector::from(&[1., 2., 3., 4.]);
+
+        let result = jacobi(&a, &b, &SolverOptions::default()).unwrap();
+
+        assert!(result.converged());
+        let mut r = b.clone();
+        r.sub(&a.mul_vec(&result.solution));
+        assert!(r.norm_2() < 1e-8);
+    }
+
+    #[test]
+    fn gauss_seidel_needs_fewer_iterations_than_jacobi() {
+        let a = poisson(8);
+        let b = Vector::from(&[1.; 8]);
+        let options = SolverOptions::new(1e-8, 5000);
+
+        let j = jacobi(&a, &b, &options).unwrap();
+        let gs = gauss_seidel(&a, &b, &options).unwrap();
+
+        assert!(j.converged());
+        assert!(gs.converged());
+        assert!(gs.iterations < j.iterations);
+    }
+
+    #[test]
+    fn zero_diagonal_is_rejected() {
+        let a = CsrMatrix::from_dense(&crate::matrix::Matrix::from(&[&[1., 1.], &[1., 0.]]));
+        let b = Vector::from(&[1., 1.]);
+        let options = SolverOptions::default();
+        let message = "Splitting methods need a non-zero diagonal entry in row 1";
+        assert_eq!(jacobi(&a, &b, &options), Err(message.to_string()));
+        assert_eq!(gauss_seidel(&a, &b, &options), Err(message.to_string()));
+    }
+}
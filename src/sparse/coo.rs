@@ -0,0 +1,137 @@
+use crate::{matrix::Matrix, numeric::Numeric};
+
+use super::{compress, dense_shape, CscMatrix, CsrMatrix};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CooMatrix<K: Numeric> {
+    rows: usize,
+    cols: usize,
+    row_indices: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<K>,
+}
+
+impl<K: Numeric> CooMatrix<K> {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        CooMatrix {
+            rows,
+            cols,
+            row_indices: Vec::new(),
+            col_indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    pub fn from_dense(matrix: &Matrix<K>) -> Self {
+        let (rows, cols) = dense_shape(matrix);
+        let mut result = CooMatrix::new(rows, cols);
+        for (i, row) in matrix.values.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                if value != K::zero() {
+                    result.push(i, j, value);
+                }
+            }
+        }
+        result
+    }
+
+    pub fn push(&mut self, row: usize, col: usize, value: K) {
+        if row >= self.rows || col >= self.cols {
+            panic!("Entry ({}, {}) is out of bounds", row, col);
+        }
+        self.row_indices.push(row);
+        self.col_indices.push(col);
+        self.values.push(value);
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, K)> + '_ {
+        self.row_indices
+            .iter()
+            .zip(self.col_indices.iter())
+            .zip(self.values.iter())
+            .map(|((&i, &j), &v)| (i, j, v))
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<K> {
+        let (offsets, indices, values) = compress(
+            self.rows,
+            &self.row_indices,
+            &self.col_indices,
+            &self.values,
+        );
+        CsrMatrix::from_raw_parts(self.rows, self.cols, offsets, indices, values)
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<K> {
+        let (offsets, indices, values) = compress(
+            self.cols,
+            &self.col_indices,
+            &self.row_indices,
+            &self.values,
+        );
+        CscMatrix::from_raw_parts(self.rows, self.cols, offsets, indices, values)
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        let mut result = Matrix {
+            values: vec![vec![K::zero(); self.cols]; self.rows],
+        };
+        for (i, j, value) in self.triplets() {
+            result.values[i][j] += value;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_and_to_dense_work() {
+        let mut m = CooMatrix::new(2, 3);
+        m.push(0, 1, 2.);
+        m.push(1, 2, 5.);
+        assert_eq!(m.nnz(), 2);
+        assert_eq!(m.to_dense(), Matrix::from(&[&[0., 2., 0.], &[0., 0., 5.]]));
+    }
+
+    #[test]
+    fn duplicate_entries_are_summed() {
+        let mut m = CooMatrix::new(2, 2);
+        m.push(1, 0, 1);
+        m.push(1, 0, 4);
+        m.push(0, 0, 3);
+        let csr = m.to_csr();
+        assert_eq!(csr.nnz(), 2);
+        assert_eq!(csr.to_dense(), Matrix::from(&[&[3, 0], &[5, 0]]));
+        assert_eq!(m.to_csc().to_dense(), Matrix::from(&[&[3, 0], &[5, 0]]));
+    }
+
+    #[test]
+    fn from_dense_skips_zeros() {
+        let dense = Matrix::from(&[&[1, 0, 0], &[0, 0, 2]]);
+        let m = CooMatrix::from_dense(&dense);
+        assert_eq!(m.nnz(), 2);
+        assert_eq!(m.to_dense(), dense);
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_panics_if_entry_is_out_of_bounds() {
+        let mut m = CooMatrix::new(2, 2);
+        m.push(2, 0, 1.);
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{matrix::Matrix, numeric::Numeric, vector::Vector};
+
+use super::{add_compressed, dense_shape, transpose_compressed, CooMatrix, CsrMatrix};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CscMatrix<K: Numeric> {
+    rows: usize,
+    cols: usize,
+    col_offsets: Vec<usize>,
+    row_indices: Vec<usize>,
+    values: Vec<K>,
+}
+
+impl<K: Numeric> CscMatrix<K> {
+    pub fn from_raw_parts(
+        rows: usize,
+        cols: usize,
+        col_offsets: Vec<usize>,
+        row_indices: Vec<usize>,
+        values: Vec<K>,
+    ) -> Self {
+        if col_offsets.len() != cols + 1 || col_offsets[0] != 0 {
+            panic!("Column offsets must have one entry per column plus one, starting at 0");
+        }
+        if row_indices.len() != values.len() || col_offsets[cols] != values.len() {
+            panic!("Row indices and values must match the column offsets");
+        }
+        for j in 0..cols {
+            if col_offsets[j] > col_offsets[j + 1] {
+                panic!("Column offsets must be non-decreasing");
+            }
+            let col = &row_indices[col_offsets[j]..col_offsets[j + 1]];
+            if col.windows(2).any(|w| w[0] >= w[1]) || col.iter().any(|&i| i >= rows) {
+                panic!("Row indices must be sorted, unique and in bounds");
+            }
+        }
+        CscMatrix {
+            rows,
+            cols,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+
+    pub fn from_dense(matrix: &Matrix<K>) -> Self {
+        let (rows, cols) = dense_shape(matrix);
+        let mut col_offsets = Vec::with_capacity(cols + 1);
+        let mut row_indices = Vec::new();
+        let mut values = Vec::new();
+        col_offsets.push(0);
+        for j in 0..cols {
+            for (i, row) in matrix.values.iter().enumerate() {
+                if row[j] != K::zero() {
+                    row_indices.push(i);
+                    values.push(row[j]);
+                }
+            }
+            col_offsets.push(values.len());
+        }
+        CscMatrix {
+            rows,
+            cols,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn col_offsets(&self) -> &[usize] {
+        &self.col_offsets
+    }
+
+    pub fn row_indices(&self) -> &[usize] {
+        &self.row_indices
+    }
+
+    pub fn values(&self) -> &[K] {
+        &self.values
+    }
+
+    pub fn col(&self, j: usize) -> impl Iterator<Item = (usize, K)> + '_ {
+        let range = self.col_offsets[j]..self.col_offsets[j + 1];
+        self.row_indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.values[range].iter().copied())
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        if row >= self.rows || col >= self.cols {
+            panic!("Entry ({}, {}) is out of bounds", row, col);
+        }
+        let range = self.col_offsets[col]..self.col_offsets[col + 1];
+        match self.row_indices[range.clone()].binary_search(&row) {
+            Ok(k) => self.values[range.start + k],
+            Err(_) => K::zero(),
+        }
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        let mut result = Matrix {
+            values: vec![vec![K::zero(); self.cols]; self.rows],
+        };
+        for j in 0..self.cols {
+            for (i, value) in self.col(j) {
+                result.values[i][j] = value;
+            }
+        }
+        result
+    }
+
+    pub fn to_coo(&self) -> CooMatrix<K> {
+        let mut result = CooMatrix::new(self.rows, self.cols);
+        for j in 0..self.cols {
+            for (i, value) in self.col(j) {
+                result.push(i, j, value);
+            }
+        }
+        result
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<K> {
+        let (offsets, indices, values) = transpose_compressed(
+            self.cols,
+            self.rows,
+            &self.col_offsets,
+            &self.row_indices,
+            &self.values,
+        );
+        CsrMatrix::from_raw_parts(self.rows, self.cols, offsets, indices, values)
+    }
+
+    pub fn transpose(&self) -> CscMatrix<K> {
+        let (offsets, indices, values) = transpose_compressed(
+            self.cols,
+            self.rows,
+            &self.col_offsets,
+            &self.row_indices,
+            &self.values,
+        );
+        CscMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            col_offsets: offsets,
+            row_indices: indices,
+            values,
+        }
+    }
+
+    pub fn add(&mut self, m: &CscMatrix<K>) {
+        if self.rows != m.rows || self.cols != m.cols {
+            panic!("Matrices must have the same dimensions to add them");
+        }
+        let (offsets, indices, values) = add_compressed(
+            self.cols,
+            (&self.col_offsets, &self.row_indices, &self.values),
+            (&m.col_offsets, &m.row_indices, &m.values),
+        );
+        self.col_offsets = offsets;
+        self.row_indices = indices;
+        self.values = values;
+    }
+
+    pub fn scl(&mut self, a: K) {
+        self.values.iter_mut().for_each(|x| *x = *x * a);
+    }
+
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        if self.cols != vec.values.len() {
+            panic!("Matrix and vector dimensions don't match");
+        }
+        let mut result = vec![K::zero(); self.rows];
+        for (j, &x) in vec.values.iter().enumerate() {
+            for (i, a) in self.col(j) {
+                result[i] += a * x;
+            }
+        }
+        Vector { values: result }
+    }
+
+    pub fn mul_mat(&self, mat: &Matrix<K>) -> Matrix<K> {
+        if self.cols != mat.values.len() {
+            panic!("Matrix dimensions don't match");
+        }
+        let width = if mat.values.is_empty() {
+            0
+        } else {
+            mat.values[0].len()
+        };
+        let mut result = Matrix {
+            values: vec![vec![K::zero(); width]; self.rows],
+        };
+        for (k, source) in mat.values.iter().enumerate() {
+            for (i, a) in self.col(k) {
+                for (target, &b) in result.values[i].iter_mut().zip(source) {
+                    *target += a * b;
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Matrix<f64> {
+        Matrix::from(&[&[4., 0., 1.], &[0., 0., 0.], &[2., 3., 0.]])
+    }
+
+    #[test]
+    fn dense_round_trip_works() {
+        let csc = CscMatrix::from_dense(&example());
+        assert_eq!(csc.col_offsets(), &[0, 2, 3, 4]);
+        assert_eq!(csc.row_indices(), &[0, 2, 2, 0]);
+        assert_eq!(csc.to_dense(), example());
+        assert_eq!(csc.get(0, 2), 1.);
+    }
+
+    #[test]
+    fn csr_and_csc_conversions_agree() {
+        let csc = CscMatrix::from_dense(&example());
+        assert_eq!(csc.to_csr(), CsrMatrix::from_dense(&example()));
+        assert_eq!(csc.to_csr().to_csc(), csc);
+        assert_eq!(csc.to_coo().to_csc(), csc);
+    }
+
+    #[test]
+    fn mul_vec_matches_dense_multiplication() {
+        let csc = CscMatrix::from_dense(&example());
+        let v = Vector::from(&[1., 2., 3.]);
+        assert_eq!(csc.mul_vec(&v), example().mul_vec(v));
+    }
+
+    #[test]
+    fn transpose_works() {
+        let csc = CscMatrix::from_dense(&Matrix::from(&[&[1, 2, 0], &[0, 0, 3]]));
+        assert_eq!(
+            csc.transpose().to_dense(),
+            Matrix::from(&[&[1, 0], &[2, 0], &[0, 3]])
+        );
+    }
+
+    #[test]
+    fn add_and_scl_work() {
+        let mut a = CscMatrix::from_dense(&Matrix::from(&[&[1, 0], &[0, 2]]));
+        a.add(&CscMatrix::from_dense(&Matrix::from(&[&[0, 5], &[1, 0]])));
+        a.scl(3);
+        assert_eq!(a.to_dense(), Matrix::from(&[&[3, 15], &[3, 6]]));
+    }
+
+    #[test]
+    fn mul_mat_matches_dense_multiplication() {
+        let a = CscMatrix::from_dense(&example());
+        let b = Matrix::from(&[&[1., 2.], &[3., 4.], &[5., 6.]]);
+        assert_eq!(a.mul_mat(&b), example().mul_mat(b));
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_panics_if_matrices_do_not_have_same_dimensions() {
+        let mut a = CscMatrix::from_dense(&Matrix::from(&[&[1, 0], &[0, 2]]));
+        a.add(&CscMatrix::from_dense(&Matrix::from(&[&[1, 2, 3]])));
+    }
+}
@@ -0,0 +1,289 @@
+use crate::{matrix::Matrix, numeric::Numeric, vector::Vector};
+
+use super::{add_compressed, dense_shape, transpose_compressed, CooMatrix, CscMatrix};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsrMatrix<K: Numeric> {
+    rows: usize,
+    cols: usize,
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<K>,
+}
+
+impl<K: Numeric> CsrMatrix<K> {
+    pub fn from_raw_parts(
+        rows: usize,
+        cols: usize,
+        row_offsets: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<K>,
+    ) -> Self {
+        if row_offsets.len() != rows + 1 || row_offsets[0] != 0 {
+            panic!("Row offsets must have one entry per row plus one, starting at 0");
+        }
+        if col_indices.len() != values.len() || row_offsets[rows] != values.len() {
+            panic!("Column indices and values must match the row offsets");
+        }
+        for i in 0..rows {
+            if row_offsets[i] > row_offsets[i + 1] {
+                panic!("Row offsets must be non-decreasing");
+            }
+            let row = &col_indices[row_offsets[i]..row_offsets[i + 1]];
+            if row.windows(2).any(|w| w[0] >= w[1]) || row.iter().any(|&j| j >= cols) {
+                panic!("Column indices must be sorted, unique and in bounds");
+            }
+        }
+        CsrMatrix {
+            rows,
+            cols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    pub fn from_dense(matrix: &Matrix<K>) -> Self {
+        let (rows, cols) = dense_shape(matrix);
+        let mut row_offsets = Vec::with_capacity(rows + 1);
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        row_offsets.push(0);
+        for row in &matrix.values {
+            for (j, &value) in row.iter().enumerate() {
+                if value != K::zero() {
+                    col_indices.push(j);
+                    values.push(value);
+                }
+            }
+            row_offsets.push(values.len());
+        }
+        CsrMatrix {
+            rows,
+            cols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.row_offsets
+    }
+
+    pub fn col_indices(&self) -> &[usize] {
+        &self.col_indices
+    }
+
+    pub fn values(&self) -> &[K] {
+        &self.values
+    }
+
+    pub fn row(&self, i: usize) -> impl Iterator<Item = (usize, K)> + '_ {
+        let range = self.row_offsets[i]..self.row_offsets[i + 1];
+        self.col_indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.values[range].iter().copied())
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> K {
+        if row >= self.rows || col >= self.cols {
+            panic!("Entry ({}, {}) is out of bounds", row, col);
+        }
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        match self.col_indices[range.clone()].binary_search(&col) {
+            Ok(k) => self.values[range.start + k],
+            Err(_) => K::zero(),
+        }
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        let mut result = Matrix {
+            values: vec![vec![K::zero(); self.cols]; self.rows],
+        };
+        for i in 0..self.rows {
+            for (j, value) in self.row(i) {
+                result.values[i][j] = value;
+            }
+        }
+        result
+    }
+
+    pub fn to_coo(&self) -> CooMatrix<K> {
+        let mut result = CooMatrix::new(self.rows, self.cols);
+        for i in 0..self.rows {
+            for (j, value) in self.row(i) {
+                result.push(i, j, value);
+            }
+        }
+        result
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<K> {
+        let (offsets, indices, values) = transpose_compressed(
+            self.rows,
+            self.cols,
+            &self.row_offsets,
+            &self.col_indices,
+            &self.values,
+        );
+        CscMatrix::from_raw_parts(self.rows, self.cols, offsets, indices, values)
+    }
+
+    pub fn transpose(&self) -> CsrMatrix<K> {
+        let (offsets, indices, values) = transpose_compressed(
+            self.rows,
+            self.cols,
+            &self.row_offsets,
+            &self.col_indices,
+            &self.values,
+        );
+        CsrMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_offsets: offsets,
+            col_indices: indices,
+            values,
+        }
+    }
+
+    pub fn add(&mut self, m: &CsrMatrix<K>) {
+        if self.rows != m.rows || self.cols != m.cols {
+            panic!("Matrices must have the same dimensions to add them");
+        }
+        let (offsets, indices, values) = add_compressed(
+            self.rows,
+            (&self.row_offsets, &self.col_indices, &self.values),
+            (&m.row_offsets, &m.col_indices, &m.values),
+        );
+        self.row_offsets = offsets;
+        self.col_indices = indices;
+        self.values = values;
+    }
+
+    pub fn scl(&mut self, a: K) {
+        self.values.iter_mut().for_each(|x| *x = *x * a);
+    }
+
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        if self.cols != vec.values.len() {
+            panic!("Matrix and vector dimensions don't match");
+        }
+        let result: Vec<K> = (0..self.rows)
+            .map(|i| {
+                self.row(i)
+                    .fold(K::zero(), |acc, (j, a)| acc + a * vec.values[j])
+            })
+            .collect();
+        Vector { values: result }
+    }
+
+    pub fn mul_mat(&self, mat: &Matrix<K>) -> Matrix<K> {
+        if self.cols != mat.values.len() {
+            panic!("Matrix dimensions don't match");
+        }
+        let width = if mat.values.is_empty() {
+            0
+        } else {
+            mat.values[0].len()
+        };
+        let mut result = Matrix {
+            values: vec![vec![K::zero(); width]; self.rows],
+        };
+        for i in 0..self.rows {
+            for (k, a) in self.row(i) {
+                for (target, &b) in result.values[i].iter_mut().zip(&mat.values[k]) {
+                    *target += a * b;
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Matrix<f64> {
+        Matrix::from(&[&[4., 0., 1.], &[0., 0., 0.], &[2., 3., 0.]])
+    }
+
+    #[test]
+    fn dense_round_trip_works() {
+        let csr = CsrMatrix::from_dense(&example());
+        assert_eq!(csr.nnz(), 4);
+        assert_eq!(csr.row_offsets(), &[0, 2, 2, 4]);
+        assert_eq!(csr.col_indices(), &[0, 2, 0, 1]);
+        assert_eq!(csr.to_dense(), example());
+        assert_eq!(csr.get(2, 1), 3.);
+        assert_eq!(csr.get(1, 1), 0.);
+    }
+
+    #[test]
+    fn mul_vec_matches_dense_multiplication() {
+        let csr = CsrMatrix::from_dense(&example());
+        let v = Vector::from(&[1., 2., 3.]);
+        assert_eq!(csr.mul_vec(&v), example().mul_vec(v));
+    }
+
+    #[test]
+    fn transpose_works() {
+        let csr = CsrMatrix::from_dense(&Matrix::from(&[&[1, 2, 0], &[0, 0, 3]]));
+        assert_eq!(
+            csr.transpose().to_dense(),
+            Matrix::from(&[&[1, 0], &[2, 0], &[0, 3]])
+        );
+    }
+
+    #[test]
+    fn add_merges_patterns_and_drops_cancelled_entries() {
+        let mut a = CsrMatrix::from_dense(&Matrix::from(&[&[1, 0], &[0, 2]]));
+        let b = CsrMatrix::from_dense(&Matrix::from(&[&[0, 5], &[0, -2]]));
+        a.add(&b);
+        assert_eq!(a.nnz(), 2);
+        assert_eq!(a.to_dense(), Matrix::from(&[&[1, 5], &[0, 0]]));
+    }
+
+    #[test]
+    fn scl_works() {
+        let mut a = CsrMatrix::from_dense(&example());
+        a.scl(2.);
+        let mut expected = example();
+        expected.scl(2.);
+        assert_eq!(a.to_dense(), expected);
+    }
+
+    #[test]
+    fn mul_mat_matches_dense_multiplication() {
+        let a = CsrMatrix::from_dense(&example());
+        let b = Matrix::from(&[&[1., 2.], &[3., 4.], &[5., 6.]]);
+        assert_eq!(a.mul_mat(&b), example().mul_mat(b));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_vec_panics_if_dimensions_dont_match() {
+        let a = CsrMatrix::from_dense(&example());
+        a.mul_vec(&Vector::from(&[1., 2.]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_raw_parts_panics_on_unsorted_columns() {
+        CsrMatrix::from_raw_parts(1, 3, vec![0, 2], vec![2, 0], vec![1., 1.]);
+    }
+}
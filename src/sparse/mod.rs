@@ -0,0 +1,115 @@
+mod coo;
+mod csc;
+mod csr;
+
+pub use coo::CooMatrix;
+pub use csc::CscMatrix;
+pub use csr::CsrMatrix;
+
+use crate::{matrix::Matrix, numeric::Numeric};
+
+fn dense_shape<K: Numeric>(matrix: &Matrix<K>) -> (usize, usize) {
+    let rows = matrix.values.len();
+    let cols = if rows > 0 { matrix.values[0].len() } else { 0 };
+    if matrix.values.iter().any(|row| row.len() != cols) {
+        panic!("Matrix rows must all have the same length");
+    }
+    (rows, cols)
+}
+
+// Turns per-row (or per-column) counts into offsets and groups the entries by
+// their major index, keeping the minor indices sorted and summing duplicates.
+fn compress<K: Numeric>(
+    major_len: usize,
+    major: &[usize],
+    minor: &[usize],
+    values: &[K],
+) -> (Vec<usize>, Vec<usize>, Vec<K>) {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by_key(|&i| (major[i], minor[i]));
+
+    let mut offsets = vec![0; major_len + 1];
+    let mut indices: Vec<usize> = Vec::with_capacity(values.len());
+    let mut data: Vec<K> = Vec::with_capacity(values.len());
+    let mut last: Option<(usize, usize)> = None;
+    for i in order {
+        let key = (major[i], minor[i]);
+        if last == Some(key) {
+            *data.last_mut().unwrap() += values[i];
+            continue;
+        }
+        offsets[key.0 + 1] += 1;
+        indices.push(key.1);
+        data.push(values[i]);
+        last = Some(key);
+    }
+    for i in 0..major_len {
+        offsets[i + 1] += offsets[i];
+    }
+    (offsets, indices, data)
+}
+
+// Transposes compressed storage: CSR arrays of A become CSR arrays of A^T, which
+// are the same as the CSC arrays of A.
+fn transpose_compressed<K: Numeric>(
+    major_len: usize,
+    minor_len: usize,
+    offsets: &[usize],
+    indices: &[usize],
+    values: &[K],
+) -> (Vec<usize>, Vec<usize>, Vec<K>) {
+    let mut new_offsets = vec![0; minor_len + 1];
+    for &index in indices {
+        new_offsets[index + 1] += 1;
+    }
+    for i in 0..minor_len {
+        new_offsets[i + 1] += new_offsets[i];
+    }
+    let mut next = new_offsets.clone();
+    let mut new_indices = vec![0; indices.len()];
+    let mut new_values = vec![K::zero(); values.len()];
+    for major in 0..major_len {
+        for k in offsets[major]..offsets[major + 1] {
+            let slot = next[indices[k]];
+            new_indices[slot] = major;
+            new_values[slot] = values[k];
+            next[indices[k]] += 1;
+        }
+    }
+    (new_offsets, new_indices, new_values)
+}
+
+// Adds two compressed matrices with identical shape, merging the sorted minor
+// indices of every major slice and dropping entries that cancel out.
+fn add_compressed<K: Numeric>(
+    major_len: usize,
+    (a_offsets, a_indices, a_values): (&[usize], &[usize], &[K]),
+    (b_offsets, b_indices, b_values): (&[usize], &[usize], &[K]),
+) -> (Vec<usize>, Vec<usize>, Vec<K>) {
+    let mut offsets = vec![0; major_len + 1];
+    let mut indices = Vec::with_capacity(a_indices.len() + b_indices.len());
+    let mut values = Vec::with_capacity(a_values.len() + b_values.len());
+    for major in 0..major_len {
+        let (mut i, a_end) = (a_offsets[major], a_offsets[major + 1]);
+        let (mut j, b_end) = (b_offsets[major], b_offsets[major + 1]);
+        while i < a_end || j < b_end {
+            let (index, value) = if j == b_end || (i < a_end && a_indices[i] < b_indices[j]) {
+                i += 1;
+                (a_indices[i - 1], a_values[i - 1])
+            } else if i == a_end || b_indices[j] < a_indices[i] {
+                j += 1;
+                (b_indices[j - 1], b_values[j - 1])
+            } else {
+                i += 1;
+                j += 1;
+                (a_indices[i - 1], a_values[i - 1] + b_values[j - 1])
+            };
+            if value != K::zero() {
+                indices.push(index);
+                values.push(value);
+            }
+        }
+        offsets[major + 1] = indices.len();
+    }
+    (offsets, indices, values)
+}
@@ -0,0 +1,208 @@
+use num::{Float, NumCast, One, ToPrimitive, Zero};
+
+use crate::linear_interpolation::Interpolate;
+
+fn scalar<P: Interpolate>(n: usize) -> P::Scalar {
+    <P::Scalar as NumCast>::from(n).unwrap()
+}
+
+fn constant<P: Interpolate>(x: f64) -> P::Scalar {
+    <P::Scalar as NumCast>::from(x).unwrap()
+}
+
+// Cubic between p0 (t = 0) and p1 (t = 1) with tangents m0 and m1.
+pub fn hermite<P: Interpolate>(p0: &P, m0: &P, p1: &P, m1: &P, t: P::Scalar) -> P {
+    let (two, three) = (constant::<P>(2.), constant::<P>(3.));
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let h00 = two * t3 - three * t2 + P::Scalar::one();
+    let h10 = t3 - two * t2 + t;
+    let h01 = three * t2 - two * t3;
+    let h11 = t3 - t2;
+    p0.scale(h00)
+        .add_scaled(m0, h10)
+        .add_scaled(p1, h01)
+        .add_scaled(m1, h11)
+}
+
+// Uniform Catmull-Rom segment from p1 (t = 0) to p2 (t = 1).
+pub fn catmull_rom<P: Interpolate>(p0: &P, p1: &P, p2: &P, p3: &P, t: P::Scalar) -> P {
+    let half = constant::<P>(0.5);
+    let m1 = p2.scale(half).add_scaled(p0, -half);
+    let m2 = p3.scale(half).add_scaled(p1, -half);
+    hermite(p1, &m1, p2, &m2, t)
+}
+
+// Catmull-Rom spline through all `points`, with t in [0, 1] spread evenly over
+// the segments. The end points are repeated to give the outer segments tangents.
+pub fn catmull_rom_spline<P: Interpolate>(points: &[P], t: P::Scalar) -> P {
+    if points.is_empty() {
+        panic!("Need at least one point to interpolate");
+    }
+    let n = points.len();
+    if n == 1 {
+        return points[0].clone();
+    }
+    let t = t.max(P::Scalar::zero()).min(P::Scalar::one());
+    let u = t * scalar::<P>(n - 1);
+    let i = u.floor().to_usize().unwrap().min(n - 2);
+    let local = u - scalar::<P>(i);
+    let p0 = &points[i.saturating_sub(1)];
+    let p3 = &points[(i + 2).min(n - 1)];
+    catmull_rom(p0, &points[i], &points[i + 1], p3, local)
+}
+
+// Bezier curve evaluated with de Casteljau's algorithm.
+pub fn bezier<P: Interpolate>(control_points: &[P], t: P::Scalar) -> P {
+    if control_points.is_empty() {
+        panic!("Need at least one control point");
+    }
+    let mut points = control_points.to_vec();
+    for level in (1..points.len()).rev() {
+        for i in 0..level {
+            points[i] = P::lerp(points[i].clone(), points[i + 1].clone(), t);
+        }
+    }
+    points.swap_remove(0)
+}
+
+// Knot vector for a B-spline that starts and ends at its end control points.
+pub fn clamped_knots<T: Float>(count: usize, degree: usize) -> Vec<T> {
+    if count <= degree {
+        panic!("Need more control points than the degree");
+    }
+    let interior = count - degree;
+    let mut knots = vec![T::zero(); degree + 1];
+    for i in 1..interior {
+        knots.push(T::from(i).unwrap() / T::from(interior).unwrap());
+    }
+    knots.extend(vec![T::one(); degree + 1]);
+    knots
+}
+
+// B-spline evaluated with de Boor's algorithm. `knots` needs
+// control_points.len() + degree + 1 non-decreasing entries and t must lie in
+// [knots[degree], knots[control_points.len()]].
+pub fn b_spline<P: Interpolate>(
+    control_points: &[P],
+    degree: usize,
+    knots: &[P::Scalar],
+    t: P::Scalar,
+) -> P {
+    let n = control_points.len();
+    if n <= degree {
+        panic!("Need more control points than the degree");
+    }
+    if knots.len() != n + degree + 1 {
+        panic!("Need control points + degree + 1 knots");
+    }
+    if knots.windows(2).any(|w| w[0] > w[1]) {
+        panic!("Knots must be non-decreasing");
+    }
+    if t < knots[degree] || t > knots[n] {
+        panic!("Parameter is outside the spline's domain");
+    }
+    let span = (degree..n)
+        .rev()
+        .find(|&k| knots[k] <= t && (t < knots[k + 1] || k == n - 1))
+        .unwrap();
+    let mut d: Vec<P> = control_points[span - degree..=span].to_vec();
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let left = knots[j + span - degree];
+            let right = knots[j + 1 + span - r];
+            let alpha = if right == left {
+                P::Scalar::zero()
+            } else {
+                (t - left) / (right - left)
+            };
+            d[j] = P::lerp(d[j - 1].clone(), d[j].clone(), alpha);
+        }
+    }
+    d.swap_remove(degree)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector::Vector;
+
+    fn assert_vector_close(a: &Vector<f64>, b: &[f64]) {
+        for (x, y) in a.values.iter().zip(b) {
+            assert!((x - y).abs() < 1e-12, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn hermite_matches_end_points_and_tangents() {
+        let (p0, m0, p1, m1) = (1., 2., 3., -1.);
+        assert_eq!(hermite(&p0, &m0, &p1, &m1, 0.), 1.);
+        assert_eq!(hermite(&p0, &m0, &p1, &m1, 1.), 3.);
+        let h = 1e-6;
+        let slope = (hermite(&p0, &m0, &p1, &m1, h) - hermite(&p0, &m0, &p1, &m1, 0.)) / h;
+        assert!((slope - 2.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn catmull_rom_spline_passes_through_points() {
+        let points = [
+            Vector::from(&[0., 0.]),
+            Vector::from(&[1., 2.]),
+            Vector::from(&[3., 3.]),
+            Vector::from(&[4., 0.]),
+        ];
+        assert_vector_close(&catmull_rom_spline(&points, 0.), &[0., 0.]);
+        assert_vector_close(&catmull_rom_spline(&points, 1. / 3.), &[1., 2.]);
+        assert_vector_close(&catmull_rom_spline(&points, 2. / 3.), &[3., 3.]);
+        assert_vector_close(&catmull_rom_spline(&points, 1.), &[4., 0.]);
+        // Midpoint of the middle segment: (-p0 + 9 p1 + 9 p2 - p3) / 16.
+        assert_vector_close(&catmull_rom_spline(&points, 0.5), &[2., 2.8125]);
+    }
+
+    #[test]
+    fn bezier_works() {
+        let points = [
+            Vector::from(&[0., 0.]),
+            Vector::from(&[1., 2.]),
+            Vector::from(&[2., 0.]),
+        ];
+        assert_vector_close(&bezier(&points, 0.5), &[1., 1.]);
+        assert_vector_close(&bezier(&points, 0.), &[0., 0.]);
+        assert_vector_close(&bezier(&points, 1.), &[2., 0.]);
+        // (1 - t)^3 * 0 + 3 (1 - t)^2 t * 1 + 3 (1 - t) t^2 * 3 + t^3 * 2 at t = 0.25
+        assert!((bezier(&[0., 1., 3., 2.], 0.25) - 0.875).abs() < 1e-15);
+    }
+
+    #[test]
+    fn clamped_b_spline_of_full_degree_is_bezier() {
+        let points = [0., 1., 3., 2.];
+        let knots = clamped_knots(points.len(), 3);
+        assert_eq!(knots, vec![0., 0., 0., 0., 1., 1., 1., 1.]);
+        for t in [0., 0.25, 0.6, 1.] {
+            assert!((b_spline(&points, 3, &knots, t) - bezier(&points, t)).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn uniform_cubic_b_spline_works() {
+        let points = [
+            Vector::from(&[0., 0.]),
+            Vector::from(&[6., 0.]),
+            Vector::from(&[6., 6.]),
+            Vector::from(&[0., 6.]),
+        ];
+        let knots: Vec<f64> = (0..8).map(|k| k as f64).collect();
+        // At an interior knot the curve is (p0 + 4 p1 + p2) / 6.
+        assert_vector_close(&b_spline(&points, 3, &knots, 3.), &[5., 1.]);
+        assert_vector_close(&b_spline(&points, 3, &knots, 4.), &[5., 5.]);
+        let clamped = clamped_knots::<f64>(5, 2);
+        assert_eq!(clamped, vec![0., 0., 0., 1. / 3., 2. / 3., 1., 1., 1.]);
+        assert_eq!(b_spline(&[1., 2., 4., 3., 0.], 2, &clamped, 1.), 0.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn b_spline_panics_on_wrong_knot_count() {
+        b_spline(&[0., 1., 2.], 2, &[0., 0., 1., 1.], 0.5);
+    }
+}
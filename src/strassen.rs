@@ -0,0 +1,296 @@
+use crate::{matrix::Matrix, numeric::Numeric};
+
+pub const STRASSEN_CUTOFF: usize = 64;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Variant {
+    Strassen,
+    Winograd,
+}
+
+impl<K: Numeric> Matrix<K> {
+    pub fn mul_mat_strassen(&self, mat: &Matrix<K>) -> Matrix<K> {
+        self.mul_mat_strassen_with_cutoff(mat, STRASSEN_CUTOFF)
+    }
+
+    pub fn mul_mat_strassen_with_cutoff(&self, mat: &Matrix<K>, cutoff: usize) -> Matrix<K> {
+        recursive_product(self, mat, cutoff, Variant::Strassen)
+    }
+
+    pub fn mul_mat_winograd(&self, mat: &Matrix<K>) -> Matrix<K> {
+        self.mul_mat_winograd_with_cutoff(mat, STRASSEN_CUTOFF)
+    }
+
+    pub fn mul_mat_winograd_with_cutoff(&self, mat: &Matrix<K>, cutoff: usize) -> Matrix<K> {
+        recursive_product(self, mat, cutoff, Variant::Winograd)
+    }
+}
+
+// Recursion only continues while every dimension is above the cutoff, so long
+// thin products go straight to the classical kernel. An odd dimension gets one
+// zero row or column at that level; the other dimensions are left alone.
+fn recursive_product<K: Numeric>(
+    a: &Matrix<K>,
+    b: &Matrix<K>,
+    cutoff: usize,
+    variant: Variant,
+) -> Matrix<K> {
+    let rows = a.values.len();
+    let inner = b.values.len();
+    let cols = b.values.first().map_or(0, |row| row.len());
+    if a.values.first().is_some_and(|row| row.len() != inner) {
+        panic!("Matrix dimensions don't match");
+    }
+    if rows == 0 || inner == 0 || cols == 0 {
+        return Matrix {
+            values: vec![vec![K::zero(); cols]; rows],
+        };
+    }
+    let shape = Shape { rows, inner, cols };
+    let product = multiply(&flatten(a), &flatten(b), shape, cutoff.max(1), variant);
+    Matrix {
+        values: product.chunks(cols).map(|row| row.to_vec()).collect(),
+    }
+}
+
+// An (rows x inner) by (inner x cols) product.
+#[derive(Clone, Copy)]
+struct Shape {
+    rows: usize,
+    inner: usize,
+    cols: usize,
+}
+
+fn flatten<K: Numeric>(m: &Matrix<K>) -> Vec<K> {
+    let cols = m.values[0].len();
+    if m.values.iter().any(|row| row.len() != cols) {
+        panic!("Matrix rows must all have the same length");
+    }
+    m.values.concat()
+}
+
+fn classical<K: Numeric>(a: &[K], b: &[K], shape: Shape) -> Vec<K> {
+    let Shape { rows, inner, cols } = shape;
+    let mut c = vec![K::zero(); rows * cols];
+    for i in 0..rows {
+        for k in 0..inner {
+            let x = a[i * inner + k];
+            for j in 0..cols {
+                c[i * cols + j] += x * b[k * cols + j];
+            }
+        }
+    }
+    c
+}
+
+// Copies a rows x cols buffer into the top-left corner of a zeroed one.
+fn grow<K: Numeric>(m: &[K], rows: usize, cols: usize, new_rows: usize, new_cols: usize) -> Vec<K> {
+    if (rows, cols) == (new_rows, new_cols) {
+        return m.to_vec();
+    }
+    let mut result = vec![K::zero(); new_rows * new_cols];
+    for i in 0..rows {
+        result[i * new_cols..i * new_cols + cols].copy_from_slice(&m[i * cols..(i + 1) * cols]);
+    }
+    result
+}
+
+// One of the four blocks of an even-sized rows x cols buffer.
+fn quadrant<K: Numeric>(m: &[K], rows: usize, cols: usize, row: usize, col: usize) -> Vec<K> {
+    let (h, w) = (rows / 2, cols / 2);
+    let mut result = Vec::with_capacity(h * w);
+    for i in 0..h {
+        let start = (row * h + i) * cols + col * w;
+        result.extend_from_slice(&m[start..start + w]);
+    }
+    result
+}
+
+fn quadrants<K: Numeric>(m: &[K], rows: usize, cols: usize) -> (Vec<K>, Vec<K>, Vec<K>, Vec<K>) {
+    (
+        quadrant(m, rows, cols, 0, 0),
+        quadrant(m, rows, cols, 0, 1),
+        quadrant(m, rows, cols, 1, 0),
+        quadrant(m, rows, cols, 1, 1),
+    )
+}
+
+fn add<K: Numeric>(a: &[K], b: &[K]) -> Vec<K> {
+    a.iter().zip(b).map(|(&x, &y)| x + y).collect()
+}
+
+fn sub<K: Numeric>(a: &[K], b: &[K]) -> Vec<K> {
+    a.iter().zip(b).map(|(&x, &y)| x - y).collect()
+}
+
+fn multiply<K: Numeric>(a: &[K], b: &[K], shape: Shape, cutoff: usize, variant: Variant) -> Vec<K> {
+    let Shape { rows, inner, cols } = shape;
+    if rows.min(inner).min(cols) <= cutoff {
+        return classical(a, b, shape);
+    }
+    let even = |n: usize| n + n % 2;
+    if rows % 2 == 1 || inner % 2 == 1 || cols % 2 == 1 {
+        let padded = Shape {
+            rows: even(rows),
+            inner: even(inner),
+            cols: even(cols),
+        };
+        let product = multiply(
+            &grow(a, rows, inner, padded.rows, padded.inner),
+            &grow(b, inner, cols, padded.inner, padded.cols),
+            padded,
+            cutoff,
+            variant,
+        );
+        return (0..rows)
+            .flat_map(|i| product[i * padded.cols..i * padded.cols + cols].to_vec())
+            .collect();
+    }
+
+    let (h, w) = (rows / 2, cols / 2);
+    let (a11, a12, a21, a22) = quadrants(a, rows, inner);
+    let (b11, b12, b21, b22) = quadrants(b, inner, cols);
+    let half = Shape {
+        rows: h,
+        inner: inner / 2,
+        cols: w,
+    };
+    let mul = |x: &[K], y: &[K]| multiply(x, y, half, cutoff, variant);
+
+    let (c11, c12, c21, c22) = match variant {
+        Variant::Strassen => {
+            let m1 = mul(&add(&a11, &a22), &add(&b11, &b22));
+            let m2 = mul(&add(&a21, &a22), &b11);
+            let m3 = mul(&a11, &sub(&b12, &b22));
+            let m4 = mul(&a22, &sub(&b21, &b11));
+            let m5 = mul(&add(&a11, &a12), &b22);
+            let m6 = mul(&sub(&a21, &a11), &add(&b11, &b12));
+            let m7 = mul(&sub(&a12, &a22), &add(&b21, &b22));
+            (
+                add(&sub(&add(&m1, &m4), &m5), &m7),
+                add(&m3, &m5),
+                add(&m2, &m4),
+                add(&add(&sub(&m1, &m2), &m3), &m6),
+            )
+        }
+        Variant::Winograd => {
+            let s1 = add(&a21, &a22);
+            let s2 = sub(&s1, &a11);
+            let s3 = sub(&a11, &a21);
+            let s4 = sub(&a12, &s2);
+            let t1 = sub(&b12, &b11);
+            let t2 = sub(&b22, &t1);
+            let t3 = sub(&b22, &b12);
+            let t4 = sub(&t2, &b21);
+            let p1 = mul(&a11, &b11);
+            let p2 = mul(&a12, &b21);
+            let p3 = mul(&s4, &b22);
+            let p4 = mul(&a22, &t4);
+            let p5 = mul(&s1, &t1);
+            let p6 = mul(&s2, &t2);
+            let p7 = mul(&s3, &t3);
+            let u2 = add(&p1, &p6);
+            let u3 = add(&u2, &p7);
+            let u4 = add(&u2, &p5);
+            (add(&p1, &p2), add(&u4, &p3), sub(&u3, &p4), add(&u3, &p5))
+        }
+    };
+
+    let mut c = vec![K::zero(); rows * cols];
+    for i in 0..h {
+        c[i * cols..i * cols + w].copy_from_slice(&c11[i * w..(i + 1) * w]);
+        c[i * cols + w..(i + 1) * cols].copy_from_slice(&c12[i * w..(i + 1) * w]);
+        c[(i + h) * cols..(i + h) * cols + w].copy_from_slice(&c21[i * w..(i + 1) * w]);
+        c[(i + h) * cols + w..(i + h + 1) * cols].copy_from_slice(&c22[i * w..(i + 1) * w]);
+    }
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn integers(rows: usize, cols: usize, seed: i32) -> Matrix<i32> {
+        Matrix {
+            values: (0..rows as i32)
+                .map(|i| {
+                    (0..cols as i32)
+                        .map(|j| (i * 13 + j * 7 + seed) % 9 - 4)
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn strassen_matches_classical_product_for_integers() {
+        for (rows, inner, cols) in [
+            (8, 8, 8),
+            (9, 9, 9),
+            (13, 7, 5),
+            (1, 20, 3),
+            (33, 17, 40),
+            (12, 31, 6),
+        ] {
+            let a = integers(rows, inner, 1);
+            let b = integers(inner, cols, 2);
+            let expected = a.mul_mat_naive(&b);
+            assert_eq!(a.mul_mat_strassen_with_cutoff(&b, 2), expected);
+            assert_eq!(a.mul_mat_winograd_with_cutoff(&b, 2), expected);
+        }
+    }
+
+    #[test]
+    fn strassen_is_close_to_classical_product_for_floats() {
+        let a = Matrix {
+            values: (0..37)
+                .map(|i| (0..37).map(|j| ((i * 37 + j) as f64).sin()).collect())
+                .collect(),
+        };
+        let b = a.clone();
+        let expected = a.mul_mat_naive(&b);
+        for result in [
+            a.mul_mat_strassen_with_cutoff(&b, 4),
+            a.mul_mat_winograd_with_cutoff(&b, 4),
+        ] {
+            for (row, expected_row) in result.values.iter().zip(&expected.values) {
+                for (x, y) in row.iter().zip(expected_row) {
+                    assert!((x - y).abs() < 1e-10);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn below_cutoff_uses_classical_kernel() {
+        let a = Matrix::from(&[&[1., 2.], &[3., 4.]]);
+        let b = Matrix::from(&[&[5., 6.], &[7., 8.]]);
+        assert_eq!(a.mul_mat_strassen(&b), a.mul_mat_naive(&b));
+    }
+
+    #[test]
+    fn thin_products_use_the_classical_kernel() {
+        let a = integers(1, 1000, 1);
+        let b = integers(1000, 1, 2);
+        assert_eq!(a.mul_mat_strassen_with_cutoff(&b, 2), a.mul_mat_naive(&b));
+        assert_eq!(a.mul_mat_winograd_with_cutoff(&b, 2), a.mul_mat_naive(&b));
+    }
+
+    #[test]
+    fn empty_operands_give_empty_products() {
+        let empty: Matrix<i32> = Matrix { values: vec![] };
+        let b = integers(3, 2, 0);
+        assert_eq!(empty.mul_mat_strassen(&b), empty);
+        let a = Matrix {
+            values: vec![vec![]; 2],
+        };
+        assert_eq!(a.mul_mat_winograd(&empty), a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn strassen_panics_if_dimensions_dont_match() {
+        let a = Matrix::from(&[&[1., 2., 3.]]);
+        a.mul_mat_strassen(&a);
+    }
+}
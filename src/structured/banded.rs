@@ -0,0 +1,303 @@
+use crate::{
+    condition::unit_roundoff,
+    linear_operator::{LinearOperator, TransposeOperator},
+    matrix::Matrix,
+    numeric::Numeric,
+    vector::Vector,
+};
+
+use super::check_rhs;
+
+// Band storage: row i keeps columns i - lower ..= i + upper, so entry (i, j)
+// lives at band[i][j + lower - i].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BandedMatrix<K: Numeric> {
+    n: usize,
+    lower: usize,
+    upper: usize,
+    band: Vec<Vec<K>>,
+}
+
+// LU factors with partial pivoting. Pivoting can fill in up to `lower` extra
+// superdiagonals, so each working row is `2 * lower + upper + 1` wide. As in
+// LAPACK's gbtrf the multipliers are not permuted after they are computed, so
+// solving has to replay the row interchanges step by step.
+struct BandedLu<K: Numeric> {
+    n: usize,
+    lower: usize,
+    upper: usize,
+    work: Vec<Vec<K>>,
+    pivots: Vec<usize>,
+    singular: bool,
+}
+
+impl<K: Numeric> BandedLu<K> {
+    fn at(&self, i: usize, j: usize) -> K {
+        self.work[i][j + self.lower - i]
+    }
+
+    fn at_mut(&mut self, i: usize, j: usize) -> &mut K {
+        &mut self.work[i][j + self.lower - i]
+    }
+
+    fn last_column(&self, k: usize) -> usize {
+        (k + self.lower + self.upper + 1).min(self.n)
+    }
+
+    fn new(matrix: &BandedMatrix<K>) -> Self {
+        let (n, lower, upper) = (matrix.n, matrix.lower, matrix.upper);
+        let width = 2 * lower + upper + 1;
+        let mut lu = BandedLu {
+            n,
+            lower,
+            upper,
+            work: vec![vec![K::zero(); width]; n],
+            pivots: vec![0; n],
+            singular: false,
+        };
+        for i in 0..n {
+            for j in matrix.columns(i) {
+                *lu.at_mut(i, j) = matrix.get(i, j);
+            }
+        }
+        for k in 0..n {
+            let last_row = (k + lower).min(n - 1);
+            let pivot = (k..=last_row)
+                .max_by(|&a, &b| lu.at(a, k).magnitude().total_cmp(&lu.at(b, k).magnitude()))
+                .unwrap();
+            lu.pivots[k] = pivot;
+            if lu.at(pivot, k) == K::zero() {
+                lu.singular = true;
+                continue;
+            }
+            if pivot != k {
+                for c in k..lu.last_column(k) {
+                    let tmp = lu.at(k, c);
+                    *lu.at_mut(k, c) = lu.at(pivot, c);
+                    *lu.at_mut(pivot, c) = tmp;
+                }
+            }
+            for r in k + 1..=last_row {
+                let factor = lu.at(r, k) / lu.at(k, k);
+                *lu.at_mut(r, k) = factor;
+                for c in k + 1..lu.last_column(k) {
+                    let value = lu.at(k, c);
+                    *lu.at_mut(r, c) = lu.at(r, c) - factor * value;
+                }
+            }
+        }
+        lu
+    }
+
+    fn solve(&self, b: &Vector<K>) -> Vector<K> {
+        let mut x = b.values.clone();
+        for k in 0..self.n {
+            x.swap(k, self.pivots[k]);
+            for r in k + 1..=(k + self.lower).min(self.n - 1) {
+                x[r] = x[r] - self.at(r, k) * x[k];
+            }
+        }
+        for i in (0..self.n).rev() {
+            let sum = (i + 1..self.last_column(i)).fold(x[i], |acc, c| acc - self.at(i, c) * x[c]);
+            x[i] = sum / self.at(i, i);
+        }
+        Vector { values: x }
+    }
+
+    fn determinant(&self) -> K {
+        if self.singular {
+            return K::zero();
+        }
+        (0..self.n).fold(K::one(), |acc, k| {
+            if self.pivots[k] != k {
+                -acc * self.at(k, k)
+            } else {
+                acc * self.at(k, k)
+            }
+        })
+    }
+}
+
+impl<K: Numeric> BandedMatrix<K> {
+    pub fn new(n: usize, lower_bandwidth: usize, upper_bandwidth: usize) -> Self {
+        BandedMatrix {
+            n,
+            lower: lower_bandwidth,
+            upper: upper_bandwidth,
+            band: vec![vec![K::zero(); lower_bandwidth + upper_bandwidth + 1]; n],
+        }
+    }
+
+    pub fn from_dense(matrix: &Matrix<K>, lower_bandwidth: usize, upper_bandwidth: usize) -> Self {
+        if !matrix.is_square() {
+            panic!("Matrix must be square to be banded");
+        }
+        let mut result = BandedMatrix::new(matrix.values.len(), lower_bandwidth, upper_bandwidth);
+        for i in 0..result.n {
+            for j in result.columns(i) {
+                result.set(i, j, matrix.values[i][j]);
+            }
+        }
+        result
+    }
+
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    pub fn bandwidths(&self) -> (usize, usize) {
+        (self.lower, self.upper)
+    }
+
+    fn columns(&self, i: usize) -> std::ops::Range<usize> {
+        i.saturating_sub(self.lower)..(i + self.upper + 1).min(self.n)
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> K {
+        if i >= self.n || j >= self.n {
+            panic!("Entry ({}, {}) is out of bounds", i, j);
+        }
+        if j + self.lower < i || j > i + self.upper {
+            return K::zero();
+        }
+        self.band[i][j + self.lower - i]
+    }
+
+    pub fn set(&mut self, i: usize, j: usize, value: K) {
+        if i >= self.n || j >= self.n || j + self.lower < i || j > i + self.upper {
+            panic!("Entry ({}, {}) is outside the band", i, j);
+        }
+        self.band[i][j + self.lower - i] = value;
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        let mut result = Matrix {
+            values: vec![vec![K::zero(); self.n]; self.n],
+        };
+        for i in 0..self.n {
+            for j in self.columns(i) {
+                result.values[i][j] = self.get(i, j);
+            }
+        }
+        result
+    }
+
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        check_rhs(self.n, vec);
+        Vector {
+            values: (0..self.n)
+                .map(|i| {
+                    self.columns(i)
+                        .fold(K::zero(), |acc, j| acc + self.get(i, j) * vec.values[j])
+                })
+                .collect(),
+        }
+    }
+
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, String> {
+        check_rhs(self.n, b);
+        let lu = BandedLu::new(self);
+        if lu.singular {
+            return Err("Matrix is not invertible".to_string());
+        }
+        Ok(lu.solve(b))
+    }
+
+    // The banded LU would truncate its multipliers for integer types, so those
+    // use the fraction-free dense determinant instead.
+    pub fn determinant(&self) -> K {
+        if unit_roundoff::<K>() >= 1. {
+            return self.to_dense().determinant();
+        }
+        BandedLu::new(self).determinant()
+    }
+}
+
+impl<K: Numeric> LinearOperator<K> for BandedMatrix<K> {
+    fn shape(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+}
+
+impl<K: Numeric> TransposeOperator<K> for BandedMatrix<K> {
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        check_rhs(self.n, x);
+        let mut result = vec![K::zero(); self.n];
+        for i in 0..self.n {
+            for j in self.columns(i) {
+                result[j] += self.get(i, j) * x.values[i];
+            }
+        }
+        Vector { values: result }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> BandedMatrix<f64> {
+        let dense = Matrix::from(&[
+            &[1., 2., 3., 0., 0.],
+            &[4., 1., 1., 2., 0.],
+            &[0., 5., 2., 1., 1.],
+            &[0., 0., 3., 1., 2.],
+            &[0., 0., 0., 1., 6.],
+        ]);
+        BandedMatrix::from_dense(&dense, 1, 2)
+    }
+
+    #[test]
+    fn dense_round_trip_works() {
+        let b = example();
+        assert_eq!(b.bandwidths(), (1, 2));
+        assert_eq!(BandedMatrix::from_dense(&b.to_dense(), 1, 2), b);
+        assert_eq!(b.get(4, 0), 0.);
+        assert_eq!(b.get(1, 0), 4.);
+    }
+
+    #[test]
+    fn solve_with_pivoting_works() {
+        let b = example();
+        let expected = Vector::from(&[1., 2., -1., 0.5, 3.]);
+        let x = b.solve(&b.mul_vec(&expected)).unwrap();
+        let mut error = x.clone();
+        error.sub(&expected);
+        assert!(error.norm_2() < 1e-12);
+    }
+
+    #[test]
+    fn determinant_works() {
+        let b = BandedMatrix::from_dense(&Matrix::from(&[&[0., 1.], &[2., 3.]]), 1, 1);
+        assert_eq!(b.determinant(), -2.);
+        let b = BandedMatrix::from_dense(&Matrix::from(&[&[1., 2.], &[2., 4.]]), 1, 1);
+        assert_eq!(b.determinant(), 0.);
+        assert!(b.solve(&Vector::from(&[1., 1.])).is_err());
+    }
+
+    #[test]
+    fn integer_determinants_are_exact() {
+        let b =
+            BandedMatrix::from_dense(&Matrix::from(&[&[2, 1, 0], &[1, 2, 1], &[0, 1, 2]]), 1, 1);
+        assert_eq!(b.determinant(), 4);
+        let b = BandedMatrix::from_dense(&Matrix::from(&[&[2, 3], &[3, 1]]), 1, 1);
+        assert_eq!(b.determinant(), -7);
+    }
+
+    #[test]
+    fn nan_entries_do_not_panic() {
+        let b = BandedMatrix::from_dense(&Matrix::from(&[&[f64::NAN, 1.], &[1., 1.]]), 1, 1);
+        assert!(b.determinant().is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_panics_outside_band() {
+        let mut b = BandedMatrix::new(3, 0, 1);
+        b.set(1, 0, 1.);
+    }
+}
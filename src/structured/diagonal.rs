@@ -0,0 +1,133 @@
+use crate::{
+    linear_operator::{LinearOperator, TransposeOperator},
+    matrix::Matrix,
+    numeric::Numeric,
+    vector::Vector,
+};
+
+use super::check_rhs;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiagonalMatrix<K: Numeric> {
+    diagonal: Vec<K>,
+}
+
+impl<K: Numeric> DiagonalMatrix<K> {
+    pub fn new(diagonal: &[K]) -> Self {
+        DiagonalMatrix {
+            diagonal: diagonal.to_vec(),
+        }
+    }
+
+    pub fn from_dense(matrix: &Matrix<K>) -> Self {
+        if !matrix.is_square() {
+            panic!("Matrix must be square to take its diagonal");
+        }
+        DiagonalMatrix {
+            diagonal: matrix
+                .values
+                .iter()
+                .enumerate()
+                .map(|(i, row)| row[i])
+                .collect(),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    pub fn diagonal(&self) -> &[K] {
+        &self.diagonal
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        let n = self.size();
+        let mut result = Matrix {
+            values: vec![vec![K::zero(); n]; n],
+        };
+        for (i, &d) in self.diagonal.iter().enumerate() {
+            result.values[i][i] = d;
+        }
+        result
+    }
+
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        check_rhs(self.size(), vec);
+        Vector {
+            values: vec
+                .values
+                .iter()
+                .zip(self.diagonal.iter())
+                .map(|(&x, &d)| x * d)
+                .collect(),
+        }
+    }
+
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, String> {
+        check_rhs(self.size(), b);
+        if self.diagonal.iter().any(|&d| d == K::zero()) {
+            return Err("Matrix is not invertible".to_string());
+        }
+        Ok(Vector {
+            values: b
+                .values
+                .iter()
+                .zip(self.diagonal.iter())
+                .map(|(&x, &d)| x / d)
+                .collect(),
+        })
+    }
+
+    pub fn determinant(&self) -> K {
+        self.diagonal.iter().fold(K::one(), |acc, &d| acc * d)
+    }
+}
+
+impl<K: Numeric> LinearOperator<K> for DiagonalMatrix<K> {
+    fn shape(&self) -> (usize, usize) {
+        (self.size(), self.size())
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+}
+
+impl<K: Numeric> TransposeOperator<K> for DiagonalMatrix<K> {
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagonal_matrix_works() {
+        let d = DiagonalMatrix::new(&[2., 4., -1.]);
+        assert_eq!(d.determinant(), -8.);
+        assert_eq!(
+            d.to_dense(),
+            Matrix::from(&[&[2., 0., 0.], &[0., 4., 0.], &[0., 0., -1.]])
+        );
+        assert_eq!(DiagonalMatrix::from_dense(&d.to_dense()), d);
+    }
+
+    #[test]
+    fn solve_divides_by_the_diagonal() {
+        let d = DiagonalMatrix::new(&[2., 4., -1.]);
+        let x = d.solve(&Vector::from(&[2., 2., 3.])).unwrap();
+        assert_eq!(x, Vector::from(&[1., 0.5, -3.]));
+    }
+
+    #[test]
+    fn solve_fails_on_singular_matrix() {
+        let d = DiagonalMatrix::new(&[2., 0.]);
+        assert_eq!(
+            d.solve(&Vector::from(&[1., 1.])).unwrap_err(),
+            "Matrix is not invertible".to_string()
+        );
+    }
+}
@@ -0,0 +1,17 @@
+mod banded;
+mod diagonal;
+mod triangular;
+mod tridiagonal;
+
+pub use banded::BandedMatrix;
+pub use diagonal::DiagonalMatrix;
+pub use triangular::{Triangle, TriangularMatrix};
+pub use tridiagonal::TridiagonalMatrix;
+
+use crate::{numeric::Numeric, vector::Vector};
+
+fn check_rhs<K: Numeric>(n: usize, b: &Vector<K>) {
+    if b.values.len() != n {
+        panic!("Matrix and vector dimensions don't match");
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{
+    linear_operator::{LinearOperator, TransposeOperator},
+    matrix::Matrix,
+    numeric::Numeric,
+    vector::Vector,
+};
+
+use super::check_rhs;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Triangle {
+    Upper,
+    Lower,
+}
+
+// Row-major packed storage of the n(n+1)/2 entries in the chosen triangle. When
+// `unit_diagonal` is set the stored diagonal is ignored and treated as ones.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TriangularMatrix<K: Numeric> {
+    n: usize,
+    triangle: Triangle,
+    unit_diagonal: bool,
+    values: Vec<K>,
+}
+
+impl<K: Numeric> TriangularMatrix<K> {
+    pub fn from_dense(matrix: &Matrix<K>, triangle: Triangle, unit_diagonal: bool) -> Self {
+        if !matrix.is_square() {
+            panic!("Matrix must be square to be triangular");
+        }
+        let n = matrix.values.len();
+        let mut values = Vec::with_capacity(n * (n + 1) / 2);
+        for (i, row) in matrix.values.iter().enumerate() {
+            match triangle {
+                Triangle::Lower => values.extend_from_slice(&row[..=i]),
+                Triangle::Upper => values.extend_from_slice(&row[i..]),
+            }
+        }
+        TriangularMatrix {
+            n,
+            triangle,
+            unit_diagonal,
+            values,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    pub fn triangle(&self) -> Triangle {
+        self.triangle
+    }
+
+    pub fn is_unit_diagonal(&self) -> bool {
+        self.unit_diagonal
+    }
+
+    fn index(&self, i: usize, j: usize) -> Option<usize> {
+        match self.triangle {
+            Triangle::Lower if j <= i => Some(i * (i + 1) / 2 + j),
+            Triangle::Upper if j >= i => Some(i * self.n - i * i.saturating_sub(1) / 2 + j - i),
+            _ => None,
+        }
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> K {
+        if i >= self.n || j >= self.n {
+            panic!("Entry ({}, {}) is out of bounds", i, j);
+        }
+        if i == j && self.unit_diagonal {
+            return K::one();
+        }
+        match self.index(i, j) {
+            Some(k) => self.values[k],
+            None => K::zero(),
+        }
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        Matrix {
+            values: (0..self.n)
+                .map(|i| (0..self.n).map(|j| self.get(i, j)).collect())
+                .collect(),
+        }
+    }
+
+    fn columns(&self, i: usize) -> std::ops::Range<usize> {
+        match self.triangle {
+            Triangle::Lower => 0..i + 1,
+            Triangle::Upper => i..self.n,
+        }
+    }
+
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        check_rhs(self.n, vec);
+        Vector {
+            values: (0..self.n)
+                .map(|i| {
+                    self.columns(i)
+                        .fold(K::zero(), |acc, j| acc + self.get(i, j) * vec.values[j])
+                })
+                .collect(),
+        }
+    }
+
+    // Forward substitution for lower, back substitution for upper triangles.
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, String> {
+        check_rhs(self.n, b);
+        let mut x = b.values.clone();
+        let order: Vec<usize> = match self.triangle {
+            Triangle::Lower => (0..self.n).collect(),
+            Triangle::Upper => (0..self.n).rev().collect(),
+        };
+        for i in order {
+            let sum = self
+                .columns(i)
+                .filter(|&j| j != i)
+                .fold(x[i], |acc, j| acc - self.get(i, j) * x[j]);
+            let pivot = self.get(i, i);
+            if pivot == K::zero() {
+                return Err("Matrix is not invertible".to_string());
+            }
+            x[i] = sum / pivot;
+        }
+        Ok(Vector { values: x })
+    }
+
+    pub fn determinant(&self) -> K {
+        (0..self.n).fold(K::one(), |acc, i| acc * self.get(i, i))
+    }
+}
+
+impl<K: Numeric> LinearOperator<K> for TriangularMatrix<K> {
+    fn shape(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+}
+
+impl<K: Numeric> TransposeOperator<K> for TriangularMatrix<K> {
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        check_rhs(self.n, x);
+        let mut result = vec![K::zero(); self.n];
+        for i in 0..self.n {
+            for j in self.columns(i) {
+                result[j] += self.get(i, j) * x.values[i];
+            }
+        }
+        Vector { values: result }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dense() -> Matrix<f64> {
+        Matrix::from(&[&[2., 1., 3.], &[4., 5., 6.], &[7., 8., 9.]])
+    }
+
+    #[test]
+    fn from_dense_keeps_only_the_triangle() {
+        let lower = TriangularMatrix::from_dense(&dense(), Triangle::Lower, false);
+        assert_eq!(
+            lower.to_dense(),
+            Matrix::from(&[&[2., 0., 0.], &[4., 5., 0.], &[7., 8., 9.]])
+        );
+        let upper = TriangularMatrix::from_dense(&dense(), Triangle::Upper, true);
+        assert_eq!(
+            upper.to_dense(),
+            Matrix::from(&[&[1., 1., 3.], &[0., 1., 6.], &[0., 0., 1.]])
+        );
+    }
+
+    #[test]
+    fn substitution_solves_both_triangles() {
+        let expected = Vector::from(&[1., -1., 2.]);
+        for triangle in [Triangle::Lower, Triangle::Upper] {
+            for unit in [false, true] {
+                let t = TriangularMatrix::from_dense(&dense(), triangle, unit);
+                let x = t.solve(&t.mul_vec(&expected)).unwrap();
+                assert_eq!(x, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn determinant_is_product_of_diagonal() {
+        let t = TriangularMatrix::from_dense(&dense(), Triangle::Upper, false);
+        assert_eq!(t.determinant(), 90.);
+        let unit = TriangularMatrix::from_dense(&dense(), Triangle::Upper, true);
+        assert_eq!(unit.determinant(), 1.);
+    }
+
+    #[test]
+    fn apply_transpose_matches_dense_transpose() {
+        let t = TriangularMatrix::from_dense(&dense(), Triangle::Lower, false);
+        let x = Vector::from(&[1., 2., 3.]);
+        assert_eq!(t.apply_transpose(&x), t.to_dense().apply_transpose(&x));
+    }
+
+    #[test]
+    fn solve_fails_on_zero_diagonal() {
+        let m = Matrix::from(&[&[1., 0.], &[1., 0.]]);
+        let t = TriangularMatrix::from_dense(&m, Triangle::Lower, false);
+        assert!(t.solve(&Vector::from(&[1., 1.])).is_err());
+    }
+}
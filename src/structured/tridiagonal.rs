@@ -0,0 +1,196 @@
+use crate::{
+    linear_operator::{LinearOperator, TransposeOperator},
+    matrix::Matrix,
+    numeric::Numeric,
+    vector::Vector,
+};
+
+use super::check_rhs;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TridiagonalMatrix<K: Numeric> {
+    lower: Vec<K>,
+    diagonal: Vec<K>,
+    upper: Vec<K>,
+}
+
+impl<K: Numeric> TridiagonalMatrix<K> {
+    pub fn new(lower: &[K], diagonal: &[K], upper: &[K]) -> Self {
+        let off_diagonal = diagonal.len().saturating_sub(1);
+        if lower.len() != off_diagonal || upper.len() != off_diagonal {
+            panic!("Off-diagonals must be one shorter than the diagonal");
+        }
+        TridiagonalMatrix {
+            lower: lower.to_vec(),
+            diagonal: diagonal.to_vec(),
+            upper: upper.to_vec(),
+        }
+    }
+
+    pub fn from_dense(matrix: &Matrix<K>) -> Self {
+        if !matrix.is_square() {
+            panic!("Matrix must be square to be tridiagonal");
+        }
+        let n = matrix.values.len();
+        TridiagonalMatrix {
+            lower: (1..n).map(|i| matrix.values[i][i - 1]).collect(),
+            diagonal: (0..n).map(|i| matrix.values[i][i]).collect(),
+            upper: (1..n).map(|i| matrix.values[i - 1][i]).collect(),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        let n = self.size();
+        let mut result = Matrix {
+            values: vec![vec![K::zero(); n]; n],
+        };
+        for i in 0..n {
+            result.values[i][i] = self.diagonal[i];
+            if i > 0 {
+                result.values[i][i - 1] = self.lower[i - 1];
+                result.values[i - 1][i] = self.upper[i - 1];
+            }
+        }
+        result
+    }
+
+    fn product(&self, x: &Vector<K>, lower: &[K], upper: &[K]) -> Vector<K> {
+        check_rhs(self.size(), x);
+        let x = &x.values;
+        Vector {
+            values: (0..x.len())
+                .map(|i| {
+                    let mut sum = self.diagonal[i] * x[i];
+                    if i > 0 {
+                        sum += lower[i - 1] * x[i - 1];
+                    }
+                    if i + 1 < x.len() {
+                        sum += upper[i] * x[i + 1];
+                    }
+                    sum
+                })
+                .collect(),
+        }
+    }
+
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        self.product(vec, &self.lower, &self.upper)
+    }
+
+    // Thomas algorithm: Gaussian elimination without pivoting, which is stable
+    // for diagonally dominant or symmetric positive definite matrices.
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, String> {
+        check_rhs(self.size(), b);
+        let n = self.size();
+        let mut upper = vec![K::zero(); n];
+        let mut rhs = vec![K::zero(); n];
+        for i in 0..n {
+            let (pivot, previous) = if i == 0 {
+                (self.diagonal[0], K::zero())
+            } else {
+                (
+                    self.diagonal[i] - self.lower[i - 1] * upper[i - 1],
+                    self.lower[i - 1] * rhs[i - 1],
+                )
+            };
+            if pivot == K::zero() {
+                return Err("Matrix is not invertible without pivoting".to_string());
+            }
+            if i + 1 < n {
+                upper[i] = self.upper[i] / pivot;
+            }
+            rhs[i] = (b.values[i] - previous) / pivot;
+        }
+        for i in (0..n.saturating_sub(1)).rev() {
+            rhs[i] = rhs[i] - upper[i] * rhs[i + 1];
+        }
+        Ok(Vector { values: rhs })
+    }
+
+    pub fn determinant(&self) -> K {
+        let mut previous = K::one();
+        let mut current = K::one();
+        for i in 0..self.size() {
+            let next = if i == 0 {
+                self.diagonal[0]
+            } else {
+                self.diagonal[i] * current - self.lower[i - 1] * self.upper[i - 1] * previous
+            };
+            previous = current;
+            current = next;
+        }
+        current
+    }
+}
+
+impl<K: Numeric> LinearOperator<K> for TridiagonalMatrix<K> {
+    fn shape(&self) -> (usize, usize) {
+        (self.size(), self.size())
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+}
+
+impl<K: Numeric> TransposeOperator<K> for TridiagonalMatrix<K> {
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        self.product(x, &self.upper, &self.lower)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> TridiagonalMatrix<f64> {
+        TridiagonalMatrix::new(&[1., 1., 1.], &[4., 4., 4., 4.], &[2., 2., 2.])
+    }
+
+    #[test]
+    fn dense_round_trip_works() {
+        let t = example();
+        assert_eq!(
+            t.to_dense(),
+            Matrix::from(&[
+                &[4., 2., 0., 0.],
+                &[1., 4., 2., 0.],
+                &[0., 1., 4., 2.],
+                &[0., 0., 1., 4.],
+            ])
+        );
+        assert_eq!(TridiagonalMatrix::from_dense(&t.to_dense()), t);
+    }
+
+    #[test]
+    fn thomas_algorithm_solves_system() {
+        let t = example();
+        let expected = Vector::from(&[1., -2., 3., 0.5]);
+        let x = t.solve(&t.mul_vec(&expected)).unwrap();
+        let mut error = x.clone();
+        error.sub(&expected);
+        assert!(error.norm_2() < 1e-12);
+    }
+
+    #[test]
+    fn determinant_uses_continuant_recurrence() {
+        let t = TridiagonalMatrix::new(&[1., 3.], &[2., 5., 1.], &[-1., 2.]);
+        assert_eq!(t.determinant(), -1.);
+    }
+
+    #[test]
+    fn solve_reports_zero_pivot() {
+        let t = TridiagonalMatrix::new(&[1.], &[0., 1.], &[1.]);
+        assert!(t.solve(&Vector::from(&[1., 1.])).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_panics_on_mismatched_lengths() {
+        TridiagonalMatrix::new(&[1.], &[1., 2., 3.], &[1., 2.]);
+    }
+}
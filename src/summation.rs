@@ -0,0 +1,226 @@
+use std::{any::Any, cell::Cell};
+
+use crate::numeric::Numeric;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Summation {
+    #[default]
+    Naive,
+    Pairwise,
+    KahanBabuska,
+    Exact,
+}
+
+// The default used by `dot`, `norm_1`, `norm_2`, `linear_combination` and
+// `mul_mat`. It is kept per thread so that concurrent callers (and tests) don't
+// change each other's results.
+thread_local! {
+    static POLICY: Cell<Summation> = const { Cell::new(Summation::Naive) };
+}
+
+pub fn set_summation_policy(method: Summation) {
+    POLICY.with(|policy| policy.set(method));
+}
+
+pub fn summation_policy() -> Summation {
+    POLICY.with(|policy| policy.get())
+}
+
+pub fn sum<K: Numeric>(terms: &[K], method: Summation) -> K {
+    match method {
+        Summation::Naive => terms.iter().fold(K::zero(), |acc, &x| acc + x),
+        Summation::Pairwise => pairwise(terms),
+        Summation::KahanBabuska => kahan_babuska(terms.iter().copied()),
+        Summation::Exact => exact(terms.iter().map(|&x| (x, K::zero()))),
+    }
+}
+
+pub fn dot<K: Numeric>(a: &[K], b: &[K], method: Summation) -> K {
+    if a.len() != b.len() {
+        panic!("Vectors must be the same length");
+    }
+    match method {
+        Summation::Naive => a.iter().zip(b).fold(K::zero(), |acc, (&x, &y)| acc + x * y),
+        Summation::Pairwise => {
+            let products: Vec<K> = a.iter().zip(b).map(|(&x, &y)| x * y).collect();
+            pairwise(&products)
+        }
+        Summation::KahanBabuska => kahan_babuska(a.iter().zip(b).map(|(&x, &y)| x * y)),
+        Summation::Exact => exact(a.iter().zip(b).map(|(&x, &y)| two_product(x, y))),
+    }
+}
+
+fn pairwise<K: Numeric>(terms: &[K]) -> K {
+    if terms.len() <= 8 {
+        return terms.iter().fold(K::zero(), |acc, &x| acc + x);
+    }
+    let (left, right) = terms.split_at(terms.len() / 2);
+    pairwise(left) + pairwise(right)
+}
+
+// Neumaier's improvement of Kahan summation, which also handles terms larger
+// than the running sum. Once the total overflows or hits an infinity the
+// compensation turns into NaN, so the plain total is returned instead.
+fn kahan_babuska<K: Numeric>(terms: impl Iterator<Item = K>) -> K {
+    let mut total = K::zero();
+    let mut compensation = K::zero();
+    for x in terms {
+        let t = total + x;
+        if total.magnitude() >= x.magnitude() {
+            compensation += (total - t) + x;
+        } else {
+            compensation += (x - t) + total;
+        }
+        total = t;
+    }
+    if !total.magnitude().is_finite() {
+        return total;
+    }
+    total + compensation
+}
+
+// Knuth's branch-free TwoSum: a + b == s + e exactly for binary floats.
+fn two_sum<K: Numeric>(a: K, b: K) -> (K, K) {
+    let s = a + b;
+    let b_virtual = s - a;
+    let a_virtual = s - b_virtual;
+    (s, (a - a_virtual) + (b - b_virtual))
+}
+
+// a * b == p + e exactly for f32/f64 via a fused multiply-add. Other scalar
+// types either multiply exactly (integers) or fall back to the rounded product.
+fn two_product<K: Numeric>(a: K, b: K) -> (K, K) {
+    let p = a * b;
+    let any = (&a as &dyn Any, &b as &dyn Any, &p as &dyn Any);
+    if let (Some(&x), Some(&y), Some(&p64)) = (
+        any.0.downcast_ref::<f64>(),
+        any.1.downcast_ref::<f64>(),
+        any.2.downcast_ref::<f64>(),
+    ) {
+        return (p, cast(x.mul_add(y, -p64)));
+    }
+    if let (Some(&x), Some(&y), Some(&p32)) = (
+        any.0.downcast_ref::<f32>(),
+        any.1.downcast_ref::<f32>(),
+        any.2.downcast_ref::<f32>(),
+    ) {
+        return (p, cast(x.mul_add(y, -p32)));
+    }
+    (p, K::zero())
+}
+
+fn cast<T: 'static, K: Numeric>(value: T) -> K {
+    *(&value as &dyn Any).downcast_ref::<K>().unwrap()
+}
+
+// Shewchuk's algorithm (as in Python's math.fsum): the running total is kept as
+// a list of non-overlapping partials, so nothing is lost until the partials
+// are added up at the very end. Infinities and overflow make the partials NaN,
+// so a naive total is kept alongside and returned when it is not finite.
+fn exact<K: Numeric>(terms: impl Iterator<Item = (K, K)>) -> K {
+    let mut naive = K::zero();
+    let mut partials: Vec<K> = Vec::new();
+    let mut add = |mut x: K| {
+        let mut kept = 0;
+        for i in 0..partials.len() {
+            let (s, e) = two_sum(x, partials[i]);
+            if e != K::zero() {
+                partials[kept] = e;
+                kept += 1;
+            }
+            x = s;
+        }
+        partials.truncate(kept);
+        partials.push(x);
+    };
+    for (high, low) in terms {
+        naive += high;
+        add(high);
+        if low != K::zero() {
+            add(low);
+        }
+    }
+    if !naive.magnitude().is_finite() {
+        return naive;
+    }
+    partials.iter().fold(K::zero(), |acc, &x| acc + x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ill_conditioned() -> Vec<f64> {
+        let mut terms = vec![1e16, 1.];
+        terms.extend(vec![1.; 999]);
+        terms.push(-1e16);
+        terms
+    }
+
+    #[test]
+    fn compensated_methods_recover_lost_digits() {
+        let terms = ill_conditioned();
+        assert_eq!(sum(&terms, Summation::Naive), 0.);
+        assert_eq!(sum(&terms, Summation::KahanBabuska), 1000.);
+        assert_eq!(sum(&terms, Summation::Exact), 1000.);
+    }
+
+    #[test]
+    fn pairwise_summation_is_more_accurate_than_naive() {
+        let terms = vec![0.1f32; 1_000_000];
+        let expected = 100_000.;
+        let naive = (sum(&terms, Summation::Naive) - expected).abs();
+        let pairwise = (sum(&terms, Summation::Pairwise) - expected).abs();
+        assert!(pairwise < naive);
+        assert!(pairwise < 1.);
+    }
+
+    #[test]
+    fn exact_dot_captures_product_rounding_errors() {
+        let x = 1. + f64::EPSILON;
+        let a = [x, -1.];
+        let b = [x, 1. + 2. * f64::EPSILON];
+        let expected = f64::EPSILON * f64::EPSILON;
+        assert_eq!(dot(&a, &b, Summation::Exact), expected);
+        assert_eq!(dot(&a, &b, Summation::Naive), 0.);
+    }
+
+    #[test]
+    fn infinite_terms_give_infinite_sums() {
+        let terms = [1., f64::INFINITY, 2., 3.];
+        let overflow = [f64::MAX, f64::MAX];
+        for method in [Summation::KahanBabuska, Summation::Exact] {
+            assert_eq!(sum(&terms, method), f64::INFINITY);
+            assert_eq!(sum(&overflow, method), f64::INFINITY);
+            assert_eq!(dot(&terms, &[1.; 4], method), f64::INFINITY);
+            assert!(sum(&[f64::INFINITY, f64::NEG_INFINITY], method).is_nan());
+        }
+    }
+
+    #[test]
+    fn integer_sums_agree_for_every_method() {
+        let terms: Vec<i32> = (-50..100).collect();
+        for method in [
+            Summation::Naive,
+            Summation::Pairwise,
+            Summation::KahanBabuska,
+            Summation::Exact,
+        ] {
+            assert_eq!(sum(&terms, method), 3675);
+            assert_eq!(
+                dot(&terms, &terms, method),
+                dot(&terms, &terms, Summation::Naive)
+            );
+        }
+    }
+
+    #[test]
+    fn policy_is_per_thread() {
+        set_summation_policy(Summation::Exact);
+        assert_eq!(summation_policy(), Summation::Exact);
+        std::thread::spawn(|| assert_eq!(summation_policy(), Summation::Naive))
+            .join()
+            .unwrap();
+        set_summation_policy(Summation::Naive);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{matrix::Matrix, numeric::Numeric};
 
 impl<K: Numeric> Matrix<K> {
-    fn trace(&self) -> K {
+    pub fn trace(&self) -> K {
         if !self.is_square() {
             panic!("Matrix must be square to calculate trace");
         }
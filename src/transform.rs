@@ -0,0 +1,462 @@
+use crate::{matrix::Matrix, vector::Vector};
+
+// All matrices are row-major and act on column vectors, i.e. `m.mul_vec(v)`.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Handedness {
+    Left,
+    Right,
+}
+
+// Depth range of normalized device coordinates: [0, 1] as in Direct3D, Vulkan
+// and Metal, or [-1, 1] as in OpenGL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepthRange {
+    ZeroToOne,
+    NegativeOneToOne,
+}
+
+fn check_planes(near: f32, far: f32) {
+    if near == far {
+        panic!("Near and far planes must differ");
+    }
+}
+
+fn check_length(v: &Vector<f32>, len: usize) {
+    if v.values.len() != len {
+        panic!("Vector must have length {}", len);
+    }
+}
+
+fn normalized(v: &Vector<f32>) -> Vector<f32> {
+    v.normalized().unwrap_or_else(|e| panic!("{}", e))
+}
+
+// `fov` is the vertical field of view in radians and `ratio` is width / height.
+pub fn perspective(
+    fov: f32,
+    ratio: f32,
+    near: f32,
+    far: f32,
+    handedness: Handedness,
+    depth: DepthRange,
+) -> Matrix<f32> {
+    check_planes(near, far);
+    if ratio == 0. || fov <= 0. || fov >= std::f32::consts::PI {
+        panic!("Field of view must be in (0, pi) and ratio non-zero");
+    }
+    let f = 1. / (fov / 2.).tan();
+    let sign = match handedness {
+        Handedness::Left => 1.,
+        Handedness::Right => -1.,
+    };
+    let (z_scale, z_offset) = match depth {
+        DepthRange::ZeroToOne => (far / (far - near), -(far * near) / (far - near)),
+        DepthRange::NegativeOneToOne => (
+            (far + near) / (far - near),
+            -(2. * far * near) / (far - near),
+        ),
+    };
+    Matrix {
+        values: vec![
+            vec![f / ratio, 0., 0., 0.],
+            vec![0., f, 0., 0.],
+            vec![0., 0., sign * z_scale, z_offset],
+            vec![0., 0., sign, 0.],
+        ],
+    }
+}
+
+// The projection matrix from the ft_matrix subject: right-handed, OpenGL depth range.
+pub fn projection(fov: f32, ratio: f32, near: f32, far: f32) -> Matrix<f32> {
+    perspective(
+        fov,
+        ratio,
+        near,
+        far,
+        Handedness::Right,
+        DepthRange::NegativeOneToOne,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn orthographic(
+    left: f32,
+    right: f32,
+    bottom: f32,
+    top: f32,
+    near: f32,
+    far: f32,
+    handedness: Handedness,
+    depth: DepthRange,
+) -> Matrix<f32> {
+    check_planes(near, far);
+    if left == right || bottom == top {
+        panic!("Orthographic volume must not be empty");
+    }
+    let sign = match handedness {
+        Handedness::Left => 1.,
+        Handedness::Right => -1.,
+    };
+    let (z_scale, z_offset) = match depth {
+        DepthRange::ZeroToOne => (1. / (far - near), -near / (far - near)),
+        DepthRange::NegativeOneToOne => (2. / (far - near), -(far + near) / (far - near)),
+    };
+    Matrix {
+        values: vec![
+            vec![
+                2. / (right - left),
+                0.,
+                0.,
+                -(right + left) / (right - left),
+            ],
+            vec![
+                0.,
+                2. / (top - bottom),
+                0.,
+                -(top + bottom) / (top - bottom),
+            ],
+            vec![0., 0., sign * z_scale, z_offset],
+            vec![0., 0., 0., 1.],
+        ],
+    }
+}
+
+// View matrix for a camera at `eye` looking at `target`. Right-handed cameras
+// look down -z, left-handed ones down +z.
+pub fn look_at(
+    eye: &Vector<f32>,
+    target: &Vector<f32>,
+    up: &Vector<f32>,
+    handedness: Handedness,
+) -> Matrix<f32> {
+    check_length(eye, 3);
+    check_length(target, 3);
+    check_length(up, 3);
+    let mut forward = target.clone();
+    forward.sub(eye);
+    let forward = normalized(&forward);
+    let side = match handedness {
+        Handedness::Left => normalized(&up.cross_product(&forward)),
+        Handedness::Right => normalized(&forward.cross_product(up)),
+    };
+    let (camera_up, back) = match handedness {
+        Handedness::Left => (forward.cross_product(&side), forward),
+        Handedness::Right => {
+            let camera_up = side.cross_product(&forward);
+            let mut back = forward;
+            back.scl(-1.);
+            (camera_up, back)
+        }
+    };
+    let row = |axis: &Vector<f32>| {
+        let mut row = axis.values.clone();
+        row.push(-axis.dot(eye));
+        row
+    };
+    Matrix {
+        values: vec![
+            row(&side),
+            row(&camera_up),
+            row(&back),
+            vec![0., 0., 0., 1.],
+        ],
+    }
+}
+
+pub fn translation(offset: &Vector<f32>) -> Matrix<f32> {
+    check_length(offset, 3);
+    let mut m = Matrix::identity(4);
+    for i in 0..3 {
+        m.values[i][3] = offset.values[i];
+    }
+    m
+}
+
+pub fn scaling(factors: &Vector<f32>) -> Matrix<f32> {
+    check_length(factors, 3);
+    let mut m = Matrix::identity(4);
+    for i in 0..3 {
+        m.values[i][i] = factors.values[i];
+    }
+    m
+}
+
+// Counter-clockwise rotation by `angle` radians around `axis` (Rodrigues' formula).
+pub fn rotation(axis: &Vector<f32>, angle: f32) -> Matrix<f32> {
+    check_length(axis, 3);
+    let axis = normalized(axis);
+    let (x, y, z) = (axis.values[0], axis.values[1], axis.values[2]);
+    let (s, c) = angle.sin_cos();
+    let t = 1. - c;
+    Matrix {
+        values: vec![
+            vec![t * x * x + c, t * x * y - s * z, t * x * z + s * y, 0.],
+            vec![t * x * y + s * z, t * y * y + c, t * y * z - s * x, 0.],
+            vec![t * x * z - s * y, t * y * z + s * x, t * z * z + c, 0.],
+            vec![0., 0., 0., 1.],
+        ],
+    }
+}
+
+pub fn to_homogeneous(v: &Vector<f32>, w: f32) -> Vector<f32> {
+    check_length(v, 3);
+    let mut values = v.values.clone();
+    values.push(w);
+    Vector { values }
+}
+
+// Perspective divide; points at infinity (w == 0) keep their direction.
+pub fn from_homogeneous(v: &Vector<f32>) -> Vector<f32> {
+    check_length(v, 4);
+    let w = v.values[3];
+    let scale = if w == 0. { 1. } else { 1. / w };
+    Vector {
+        values: v.values[..3].iter().map(|x| x * scale).collect(),
+    }
+}
+
+pub fn apply(m: &Matrix<f32>, v: &Vector<f32>) -> Vector<f32> {
+    if m.values.len() != 4 || m.values.iter().any(|row| row.len() != 4) {
+        panic!("Transform must be a 4x4 matrix");
+    }
+    check_length(v, 4);
+    m.mul_vec(v.clone())
+}
+
+pub fn transform_point(m: &Matrix<f32>, point: &Vector<f32>) -> Vector<f32> {
+    from_homogeneous(&apply(m, &to_homogeneous(point, 1.)))
+}
+
+pub fn transform_direction(m: &Matrix<f32>, direction: &Vector<f32>) -> Vector<f32> {
+    let transformed = apply(m, &to_homogeneous(direction, 0.));
+    Vector {
+        values: transformed.values[..3].to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_3};
+
+    fn assert_matrix_eq(actual: &Matrix<f32>, expected: &[[f32; 4]; 4]) {
+        for (row, expected_row) in actual.values.iter().zip(expected.iter()) {
+            for (a, e) in row.iter().zip(expected_row.iter()) {
+                assert!((a - e).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+            }
+        }
+    }
+
+    fn assert_vector_eq(actual: &Vector<f32>, expected: &[f32]) {
+        for (a, e) in actual.values.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn perspective_matches_reference_matrices() {
+        // f = 1 / tan(pi / 6) = sqrt(3); near = 1, far = 3.
+        let f = 3f32.sqrt();
+        let fov = FRAC_PI_3;
+        assert_matrix_eq(
+            &projection(fov, 2., 1., 3.),
+            &[
+                [f / 2., 0., 0., 0.],
+                [0., f, 0., 0.],
+                [0., 0., -2., -3.],
+                [0., 0., -1., 0.],
+            ],
+        );
+        assert_matrix_eq(
+            &perspective(fov, 2., 1., 3., Handedness::Right, DepthRange::ZeroToOne),
+            &[
+                [f / 2., 0., 0., 0.],
+                [0., f, 0., 0.],
+                [0., 0., -1.5, -1.5],
+                [0., 0., -1., 0.],
+            ],
+        );
+        assert_matrix_eq(
+            &perspective(fov, 2., 1., 3., Handedness::Left, DepthRange::ZeroToOne),
+            &[
+                [f / 2., 0., 0., 0.],
+                [0., f, 0., 0.],
+                [0., 0., 1.5, -1.5],
+                [0., 0., 1., 0.],
+            ],
+        );
+        assert_matrix_eq(
+            &perspective(
+                fov,
+                2.,
+                1.,
+                3.,
+                Handedness::Left,
+                DepthRange::NegativeOneToOne,
+            ),
+            &[
+                [f / 2., 0., 0., 0.],
+                [0., f, 0., 0.],
+                [0., 0., 2., -3.],
+                [0., 0., 1., 0.],
+            ],
+        );
+    }
+
+    #[test]
+    fn perspective_maps_near_and_far_planes_to_depth_range() {
+        let m = projection(FRAC_PI_2, 1., 0.5, 10.);
+        assert_vector_eq(
+            &transform_point(&m, &Vector::from(&[0., 0., -0.5])),
+            &[0., 0., -1.],
+        );
+        assert_vector_eq(
+            &transform_point(&m, &Vector::from(&[0., 0., -10.])),
+            &[0., 0., 1.],
+        );
+        let m = perspective(
+            FRAC_PI_2,
+            1.,
+            0.5,
+            10.,
+            Handedness::Left,
+            DepthRange::ZeroToOne,
+        );
+        assert_vector_eq(
+            &transform_point(&m, &Vector::from(&[0., 0., 0.5])),
+            &[0., 0., 0.],
+        );
+        assert_vector_eq(
+            &transform_point(&m, &Vector::from(&[1., 0., 10.])),
+            &[0.1, 0., 1.],
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn perspective_panics_on_equal_planes() {
+        projection(FRAC_PI_2, 1., 1., 1.);
+    }
+
+    #[test]
+    fn orthographic_matches_reference_matrices() {
+        assert_matrix_eq(
+            &orthographic(
+                -2.,
+                2.,
+                -1.,
+                1.,
+                1.,
+                3.,
+                Handedness::Right,
+                DepthRange::NegativeOneToOne,
+            ),
+            &[
+                [0.5, 0., 0., 0.],
+                [0., 1., 0., 0.],
+                [0., 0., -1., -2.],
+                [0., 0., 0., 1.],
+            ],
+        );
+        assert_matrix_eq(
+            &orthographic(
+                0.,
+                4.,
+                0.,
+                2.,
+                1.,
+                3.,
+                Handedness::Left,
+                DepthRange::ZeroToOne,
+            ),
+            &[
+                [0.5, 0., 0., -1.],
+                [0., 1., 0., -1.],
+                [0., 0., 0.5, -0.5],
+                [0., 0., 0., 1.],
+            ],
+        );
+    }
+
+    #[test]
+    fn look_at_works() {
+        let eye = Vector::from(&[0., 0., 5.]);
+        let target = Vector::from(&[0., 0., 0.]);
+        let up = Vector::from(&[0., 1., 0.]);
+        assert_matrix_eq(
+            &look_at(&eye, &target, &up, Handedness::Right),
+            &[
+                [1., 0., 0., 0.],
+                [0., 1., 0., 0.],
+                [0., 0., 1., -5.],
+                [0., 0., 0., 1.],
+            ],
+        );
+        let eye = Vector::from(&[1., 2., 3.]);
+        let target = Vector::from(&[4., 2., 3.]);
+        for (handedness, depth) in [(Handedness::Right, -3.), (Handedness::Left, 3.)] {
+            let view = look_at(&eye, &target, &up, handedness);
+            assert_vector_eq(&transform_point(&view, &eye), &[0., 0., 0.]);
+            assert_vector_eq(&transform_point(&view, &target), &[0., 0., depth]);
+        }
+    }
+
+    #[test]
+    fn translation_and_scaling_work() {
+        let t = translation(&Vector::from(&[1., 2., 3.]));
+        assert_vector_eq(
+            &transform_point(&t, &Vector::from(&[1., 1., 1.])),
+            &[2., 3., 4.],
+        );
+        assert_vector_eq(
+            &transform_direction(&t, &Vector::from(&[1., 1., 1.])),
+            &[1., 1., 1.],
+        );
+        let s = scaling(&Vector::from(&[2., 3., -1.]));
+        assert_matrix_eq(
+            &s,
+            &[
+                [2., 0., 0., 0.],
+                [0., 3., 0., 0.],
+                [0., 0., -1., 0.],
+                [0., 0., 0., 1.],
+            ],
+        );
+    }
+
+    #[test]
+    fn rotation_works() {
+        let r = rotation(&Vector::from(&[0., 0., 2.]), FRAC_PI_2);
+        assert_matrix_eq(
+            &r,
+            &[
+                [0., -1., 0., 0.],
+                [1., 0., 0., 0.],
+                [0., 0., 1., 0.],
+                [0., 0., 0., 1.],
+            ],
+        );
+        // A third of a turn around (1, 1, 1) cycles the axes.
+        let r = rotation(&Vector::from(&[1., 1., 1.]), 2. * FRAC_PI_3);
+        assert_vector_eq(
+            &transform_point(&r, &Vector::from(&[1., 0., 0.])),
+            &[0., 1., 0.],
+        );
+    }
+
+    #[test]
+    fn transforms_compose() {
+        let t = translation(&Vector::from(&[1., 0., 0.]));
+        let r = rotation(&Vector::from(&[0., 0., 1.]), FRAC_PI_2);
+        let m = t.mul_mat(r);
+        assert_vector_eq(
+            &transform_point(&m, &Vector::from(&[1., 0., 0.])),
+            &[1., 1., 0.],
+        );
+        assert_vector_eq(
+            &apply(&m, &Vector::from(&[1., 0., 0., 2.])),
+            &[2., 1., 0., 2.],
+        );
+    }
+}
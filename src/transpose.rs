@@ -1,7 +1,7 @@
 use crate::{matrix::Matrix, numeric::Numeric};
 
 impl<K: Numeric> Matrix<K> {
-    fn transpose(&mut self) -> Matrix<K> {
+    pub fn transpose(&mut self) -> Matrix<K> {
         let mut result = Matrix {
             values: vec![vec![K::zero(); self.values.len()]; self.values[0].len()],
         };
@@ -1,4 +1,8 @@
-use crate::numeric::Numeric;
+use crate::{
+    numeric::Numeric,
+    simd,
+    summation::{self, summation_policy, Summation},
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Vector<K: Numeric> {
@@ -13,17 +17,6 @@ impl<K: Numeric> Vector<K> {
     }
 }
 
-impl Vector<f32> {
-    pub fn lerp(u: Vector<f32>, v: Vector<f32>, t: f32) -> Vector<f32> {
-        let mut result = u.clone();
-        result.scl(1.0 - t);
-        let mut v = v.clone();
-        v.scl(t);
-        result.add(&v);
-        result
-    }
-}
-
 impl<K> Vector<K>
 where
     K: Numeric,
@@ -32,16 +25,22 @@ where
         if self.values.len() != v.values.len() {
             panic!("Vectors must be the same length");
         }
+        if simd::add(&mut self.values, &v.values) {
+            return;
+        }
         self.values
             .iter_mut()
             .zip(v.values.iter())
-            .for_each(|(x, y)| *x = *x + *y);
+            .for_each(|(x, y)| *x += *y);
     }
 
     pub fn sub(&mut self, v: &Vector<K>) {
         if self.values.len() != v.values.len() {
             panic!("Vectors must be the same length");
         }
+        if simd::sub(&mut self.values, &v.values) {
+            return;
+        }
         self.values
             .iter_mut()
             .zip(v.values.iter())
@@ -49,13 +48,26 @@ where
     }
 
     pub fn scl(&mut self, a: K) {
+        if simd::scl(&mut self.values, a) {
+            return;
+        }
         self.values.iter_mut().for_each(|x| *x = *x * a);
     }
 
     pub fn dot(&self, v: &Vector<K>) -> K {
+        self.dot_with(v, summation_policy())
+    }
+
+    pub fn dot_with(&self, v: &Vector<K>, method: Summation) -> K {
         if self.values.len() != v.values.len() {
             panic!("Vectors must be the same length");
         }
+        if method != Summation::Naive {
+            return summation::dot(&self.values, &v.values, method);
+        }
+        if let Some(result) = simd::dot(&self.values, &v.values) {
+            return result;
+        }
         self.values
             .iter()
             .zip(v.values.iter())
@@ -154,6 +166,17 @@ mod tests {
         assert_eq!(v.dot(&v2), 2.);
     }
 
+    #[test]
+    fn dot_with_uses_requested_summation() {
+        let v = Vector::from(&[1e16, 1., -1e16]);
+        let ones = Vector::from(&[1., 1., 1.]);
+
+        assert_eq!(v.dot_with(&ones, Summation::Naive), 0.);
+        assert_eq!(v.dot_with(&ones, Summation::Pairwise), 0.);
+        assert_eq!(v.dot_with(&ones, Summation::KahanBabuska), 1.);
+        assert_eq!(v.dot_with(&ones, Summation::Exact), 1.);
+    }
+
     #[test]
     fn dot_product_returns_dot_product_3() {
         let v = Vector {